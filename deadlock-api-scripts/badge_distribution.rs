@@ -14,9 +14,9 @@
 // - Display player rank distribution to understand player skill levels
 //
 // Technical Details:
-// - Retrieve badge level (rank encoding) - `badge_level`: i32
+// - Retrieve badge level (rank encoding) - `badge_level`: u32
 //   Format: first digits = tier, last digit = subtier
-// - Retrieve total matches count - `total_matches`: i32
+// - Retrieve total matches count - `total_matches`: u64
 // - Apply time filter: matches from last 30 days (1 month)
 //
 // Output Format:
@@ -26,6 +26,8 @@
 //   - Total matches count
 //   - (visual separator between blocks)
 
+mod client;
+
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -34,7 +36,7 @@ use std::time::{SystemTime, UNIX_EPOCH};
 // ENDPOINT CONSTANTS
 // ============================================================================
 
-const BASE_URL: &str = "https://api.deadlock-api.com";
+// Base URL, API key, user agent, timeout and proxy: see client.rs
 const ENDPOINT: &str = "/v1/analytics/badge-distribution";
 
 // ============================================================================
@@ -51,8 +53,8 @@ const ENDPOINT: &str = "/v1/analytics/badge-distribution";
 
 #[derive(Debug, Serialize, Deserialize)]
 struct BadgeDistribution {
-    badge_level: i32,
-    total_matches: i32,
+    badge_level: u32,
+    total_matches: u64,
 }
 
 // ============================================================================
@@ -61,17 +63,19 @@ struct BadgeDistribution {
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
+    let config = client::Config::from_args()?;
+
     // Calculate timestamp for 30 days ago
     let now = SystemTime::now()
         .duration_since(UNIX_EPOCH)?
         .as_secs();
     let month_ago = now - (30 * 24 * 60 * 60); // 30 days in seconds
     
-    let url = format!("{}{}?min_unix_timestamp={}", BASE_URL, ENDPOINT, month_ago);
+    let url = format!("{}{}?min_unix_timestamp={}", config.base_url, ENDPOINT, month_ago);
 
     println!("Requesting badge distribution for last 30 days...");
 
-    let client = reqwest::Client::new();
+    let client = config.build_client()?;
     let response = client.get(&url).send().await?;
     let status = response.status();
 
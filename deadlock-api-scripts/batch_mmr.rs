@@ -14,7 +14,7 @@
 // - Get player rank information
 //
 // Technical Details:
-// - Retrieve player account ID - `account_id`: i32
+// - Retrieve player account ID - `account_id`: u32
 // - Retrieve rank division (tier) - `division`: i32
 // - Retrieve rank division tier (subtier) - `division_tier`: i32
 // - Retrieve player score - `player_score`: f64
@@ -27,6 +27,8 @@
 //   - player_score
 //   - (visual separator between blocks)
 
+mod client;
+
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 
@@ -34,14 +36,14 @@ use std::error::Error;
 // ENDPOINT CONSTANTS
 // ============================================================================
 
-const BASE_URL: &str = "https://api.deadlock-api.com";
+// Base URL, API key, user agent, timeout and proxy: see client.rs
 const ENDPOINT: &str = "/v1/players/mmr";
 
 // ============================================================================
 // INPUT DATA
 // ============================================================================
 
-const ACCOUNT_IDS: &[i32] = &[1133609782, 1110035791];
+const ACCOUNT_IDS: &[u32] = &[1133609782, 1110035791];
 
 // ============================================================================
 // RESPONSE STRUCTURE
@@ -49,10 +51,10 @@ const ACCOUNT_IDS: &[i32] = &[1133609782, 1110035791];
 
 #[derive(Debug, Serialize, Deserialize)]
 struct PlayerMMR {
-    account_id: i32,
+    account_id: u32,
     division: i32,
     division_tier: i32,
-    match_id: i64,
+    match_id: u64,
     player_score: f64,
     rank: i32,
     start_time: i64,
@@ -64,17 +66,19 @@ struct PlayerMMR {
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
+    let config = client::Config::from_args()?;
+
     let account_ids_str = ACCOUNT_IDS
         .iter()
         .map(|id| id.to_string())
         .collect::<Vec<String>>()
         .join(",");
 
-    let url = format!("{}{}?account_ids={}", BASE_URL, ENDPOINT, account_ids_str);
+    let url = format!("{}{}?account_ids={}", config.base_url, ENDPOINT, account_ids_str);
 
     println!("Requesting MMR for {} account(s)...", ACCOUNT_IDS.len());
 
-    let client = reqwest::Client::new();
+    let client = config.build_client()?;
     let response = client.get(&url).send().await?;
     let status = response.status();
 
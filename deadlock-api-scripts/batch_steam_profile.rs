@@ -28,6 +28,8 @@
 //   - last_updated
 //   - (visual separator between blocks)
 
+mod client;
+
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 
@@ -35,14 +37,14 @@ use std::error::Error;
 // ENDPOINT CONSTANTS
 // ============================================================================
 
-const BASE_URL: &str = "https://api.deadlock-api.com";
+// Base URL, API key, user agent, timeout and proxy: see client.rs
 const ENDPOINT: &str = "/v1/players/steam";
 
 // ============================================================================
 // INPUT DATA
 // ============================================================================
 
-const ACCOUNT_IDS: &[i32] = &[1133609782, 1110035791];
+const ACCOUNT_IDS: &[u32] = &[1133609782, 1110035791];
 
 // ============================================================================
 // RESPONSE STRUCTURE
@@ -50,7 +52,7 @@ const ACCOUNT_IDS: &[i32] = &[1133609782, 1110035791];
 
 #[derive(Debug, Serialize, Deserialize)]
 struct SteamProfile {
-    account_id: i32,
+    account_id: u32,
     avatar: String,
     avatarfull: String,
     avatarmedium: String,
@@ -67,17 +69,19 @@ struct SteamProfile {
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
+    let config = client::Config::from_args()?;
+
     let account_ids_str = ACCOUNT_IDS
         .iter()
         .map(|id| id.to_string())
         .collect::<Vec<String>>()
         .join(",");
 
-    let url = format!("{}{}?account_ids={}", BASE_URL, ENDPOINT, account_ids_str);
+    let url = format!("{}{}?account_ids={}", config.base_url, ENDPOINT, account_ids_str);
 
     println!("Requesting Steam profiles for {} accounts...", ACCOUNT_IDS.len());
 
-    let client = reqwest::Client::new();
+    let client = config.build_client()?;
     let response = client.get(&url).send().await?;
     let status = response.status();
 
@@ -0,0 +1,119 @@
+// client.rs: Shared API client configuration for all scripts
+
+// ============================================================================
+// COMMAND LINE FLAGS
+// ============================================================================
+// Every script accepts the same optional flags (environment variable fallback):
+// --base-url <URL>     API base URL (DEADLOCK_API_BASE_URL)
+// --api-key <KEY>      API key sent in the X-API-Key header (DEADLOCK_API_KEY)
+// --user-agent <UA>    User-Agent header
+// --timeout <SECS>     Request timeout in seconds (default 30)
+// --proxy <URL>        HTTP(S) or SOCKS5 proxy (http://, https://, socks5://)
+
+use std::error::Error;
+use std::time::Duration;
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+const BASE_URL: &str = "https://api.deadlock-api.com";
+const BASE_URL_ENV: &str = "DEADLOCK_API_BASE_URL";
+const API_KEY_ENV: &str = "DEADLOCK_API_KEY";
+const API_KEY_HEADER: &str = "X-API-Key";
+const USER_AGENT: &str = concat!("deadlock-api-scripts/", env!("CARGO_PKG_VERSION"));
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+const USAGE: &str = "\
+Options:
+  --base-url <URL>    API base URL (env: DEADLOCK_API_BASE_URL)
+  --api-key <KEY>     API key sent in the X-API-Key header (env: DEADLOCK_API_KEY)
+  --user-agent <UA>   User-Agent header
+  --timeout <SECS>    Request timeout in seconds (default 30)
+  --proxy <URL>       HTTP(S) or SOCKS5 proxy (http://, https://, socks5://)
+  -h, --help          Print this help";
+
+// ============================================================================
+// CONFIGURATION
+// ============================================================================
+
+pub struct Config {
+    pub base_url: String,
+    api_key: Option<String>,
+    user_agent: String,
+    timeout: Duration,
+    proxy: Option<String>,
+}
+
+impl Config {
+    // Reads flags from the command line (prints help and exits on --help)
+    pub fn from_args() -> Result<Self, Box<dyn Error>> {
+        let mut base_url = std::env::var(BASE_URL_ENV).unwrap_or_else(|_| BASE_URL.to_string());
+        let mut api_key = std::env::var(API_KEY_ENV).ok();
+        let mut user_agent = USER_AGENT.to_string();
+        let mut timeout_secs = DEFAULT_TIMEOUT_SECS;
+        let mut proxy = None;
+
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            if arg == "-h" || arg == "--help" {
+                println!("{}", USAGE);
+                std::process::exit(0);
+            }
+
+            // Both `--flag value` and `--flag=value` are accepted
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+            let mut value = || {
+                inline_value
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("Missing value for {}\n\n{}", flag, USAGE))
+            };
+
+            match flag.as_str() {
+                "--base-url" => base_url = value()?,
+                "--api-key" => api_key = Some(value()?),
+                "--user-agent" => user_agent = value()?,
+                "--timeout" => {
+                    timeout_secs = value()?
+                        .parse()
+                        .map_err(|e| format!("Invalid --timeout: {}", e))?
+                }
+                "--proxy" => proxy = Some(value()?),
+                _ => return Err(format!("Unknown argument: {}\n\n{}", flag, USAGE).into()),
+            }
+        }
+
+        Ok(Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            api_key: api_key.filter(|key| !key.is_empty()),
+            user_agent,
+            timeout: Duration::from_secs(timeout_secs),
+            proxy,
+        })
+    }
+
+    // Builds HTTP client with headers, timeout and proxy applied
+    pub fn build_client(&self) -> Result<reqwest::Client, Box<dyn Error>> {
+        let mut headers = reqwest::header::HeaderMap::new();
+        if let Some(api_key) = &self.api_key {
+            let mut value = reqwest::header::HeaderValue::from_str(api_key)?;
+            value.set_sensitive(true);
+            headers.insert(API_KEY_HEADER, value);
+        }
+
+        let mut builder = reqwest::Client::builder()
+            .default_headers(headers)
+            .user_agent(&self.user_agent)
+            .timeout(self.timeout);
+
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy)?);
+        }
+
+        Ok(builder.build()?)
+    }
+}
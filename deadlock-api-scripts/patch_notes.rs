@@ -29,6 +29,8 @@
 //   - content_preview (first 100 characters)
 //   - (visual separator between blocks)
 
+mod client;
+
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 
@@ -36,7 +38,7 @@ use std::error::Error;
 // ENDPOINT CONSTANTS
 // ============================================================================
 
-const BASE_URL: &str = "https://api.deadlock-api.com";
+// Base URL, API key, user agent, timeout and proxy: see client.rs
 const ENDPOINT: &str = "/v1/patches";
 
 // ============================================================================
@@ -80,11 +82,13 @@ struct PatchNote {
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
-    let url = format!("{}{}", BASE_URL, ENDPOINT);
+    let config = client::Config::from_args()?;
+
+    let url = format!("{}{}", config.base_url, ENDPOINT);
 
     println!("Requesting patch notes...");
 
-    let client = reqwest::Client::new();
+    let client = config.build_client()?;
     let response = client.get(&url).send().await?;
     let status = response.status();
 
@@ -0,0 +1,202 @@
+//! Runs the scripts against the local mock API (see `support`)
+
+mod support;
+
+use support::MockApi;
+
+/// (binary, endpoint) for every script
+const SCRIPTS: &[(&str, &str)] = &[
+    (env!("CARGO_BIN_EXE_batch_mmr"), "/v1/players/mmr"),
+    (
+        env!("CARGO_BIN_EXE_batch_steam_profile"),
+        "/v1/players/steam",
+    ),
+    (
+        env!("CARGO_BIN_EXE_badge_distribution"),
+        "/v1/analytics/badge-distribution",
+    ),
+    (env!("CARGO_BIN_EXE_patch_notes"), "/v1/patches"),
+];
+
+fn stdout(output: &std::process::Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &std::process::Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn batch_mmr_prints_fixture_players() {
+    let api = MockApi::start();
+    let output = api.run(env!("CARGO_BIN_EXE_batch_mmr"));
+    let out = stdout(&output);
+
+    assert!(output.status.success(), "stderr: {}", stderr(&output));
+    assert!(out.contains("=== Batch MMR ==="));
+    assert!(out.contains("Retrieved 2 player(s)"));
+    assert!(out.contains("player_id: 1133609782"));
+    assert!(out.contains("rank: 9.4"));
+    assert!(out.contains("player_score: 62.81"));
+    assert_eq!(
+        api.requests(),
+        ["/v1/players/mmr?account_ids=1133609782,1110035791"]
+    );
+}
+
+#[test]
+fn batch_steam_profile_prints_fixture_profiles() {
+    let api = MockApi::start();
+    let output = api.run(env!("CARGO_BIN_EXE_batch_steam_profile"));
+    let out = stdout(&output);
+
+    assert!(output.status.success(), "stderr: {}", stderr(&output));
+    assert!(out.contains("=== Batch Steam Profile ==="));
+    assert!(out.contains("Retrieved 2 profile(s)"));
+    assert!(out.contains("personaname: first_player"));
+    assert!(out.contains("profileurl: https://steamcommunity.com/profiles/76561199070301519/"));
+    assert_eq!(
+        api.requests(),
+        ["/v1/players/steam?account_ids=1133609782,1110035791"]
+    );
+}
+
+#[test]
+fn badge_distribution_prints_fixture_ranks() {
+    let api = MockApi::start();
+    let output = api.run(env!("CARGO_BIN_EXE_badge_distribution"));
+    let out = stdout(&output);
+
+    assert!(output.status.success(), "stderr: {}", stderr(&output));
+    assert!(out.contains("=== Badge Distribution ==="));
+    assert!(out.contains("Retrieved 4 rank(s)"));
+    assert!(out.contains("rank: 11.6"));
+    assert!(out.contains("total_matches: 412"));
+
+    let requests = api.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("/v1/analytics/badge-distribution?min_unix_timestamp="));
+}
+
+#[test]
+fn patch_notes_prints_fixture_patches() {
+    let api = MockApi::start();
+    let output = api.run(env!("CARGO_BIN_EXE_patch_notes"));
+    let out = stdout(&output);
+
+    assert!(output.status.success(), "stderr: {}", stderr(&output));
+    assert!(out.contains("=== Patch Notes ==="));
+    assert!(out.contains("title: 12-19-2024 Update"));
+    assert!(out.contains("content_preview: General- Soul orbs now last longer"));
+    assert_eq!(api.requests(), ["/v1/patches"]);
+}
+
+/// Every script must fail with the status and body of an error response
+fn assert_api_error(status: u16, body: &str) {
+    for (bin, endpoint) in SCRIPTS {
+        let api = MockApi::start();
+        api.respond_with(endpoint, status, body);
+
+        let output = api.run(bin);
+        let err = stderr(&output);
+
+        assert!(!output.status.success(), "{} succeeded on {}", bin, status);
+        assert!(
+            err.contains(&format!("API error {}", status)),
+            "{}: {}",
+            bin,
+            err
+        );
+        assert!(err.contains("mock error"), "{}: {}", bin, err);
+    }
+}
+
+#[test]
+fn scripts_report_invalid_parameters() {
+    assert_api_error(400, "mock error: invalid parameters");
+}
+
+#[test]
+fn scripts_report_data_not_found() {
+    assert_api_error(404, "mock error: not found");
+}
+
+#[test]
+fn scripts_report_rate_limit() {
+    assert_api_error(429, "mock error: rate limit exceeded");
+}
+
+#[test]
+fn scripts_report_internal_server_error() {
+    assert_api_error(500, "mock error: internal server error");
+}
+
+#[test]
+fn scripts_fail_on_malformed_response() {
+    for (bin, endpoint) in SCRIPTS {
+        let api = MockApi::start();
+        api.respond_with(endpoint, 200, r#"{"unexpected": true}"#);
+
+        let output = api.run(bin);
+        assert!(!output.status.success(), "{} accepted malformed body", bin);
+    }
+}
+
+#[test]
+fn scripts_apply_command_line_flags() {
+    for (bin, endpoint) in SCRIPTS {
+        let api = MockApi::start();
+        let output = api.run_with_args(
+            bin,
+            &[
+                "--base-url",
+                api.base_url(),
+                "--api-key=secret-key",
+                "--user-agent",
+                "mirror-check/1.0",
+                "--timeout",
+                "5",
+            ],
+        );
+
+        assert!(output.status.success(), "{}: {}", bin, stderr(&output));
+
+        let requests = api.recorded();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].url.starts_with(endpoint));
+        assert_eq!(requests[0].header("X-API-Key"), Some("secret-key"));
+        assert_eq!(requests[0].header("User-Agent"), Some("mirror-check/1.0"));
+    }
+}
+
+#[test]
+fn scripts_send_default_user_agent_without_api_key() {
+    let api = MockApi::start();
+    let output = api.run(env!("CARGO_BIN_EXE_batch_mmr"));
+
+    assert!(output.status.success(), "stderr: {}", stderr(&output));
+
+    let requests = api.recorded();
+    assert_eq!(requests[0].header("X-API-Key"), None);
+    assert!(requests[0]
+        .header("User-Agent")
+        .is_some_and(|agent| agent.starts_with("deadlock-api-scripts/")));
+}
+
+#[test]
+fn scripts_reject_invalid_flags() {
+    let api = MockApi::start();
+
+    let output = api.run_with_args(env!("CARGO_BIN_EXE_patch_notes"), &["--verbose"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("Unknown argument: --verbose"));
+
+    let output = api.run_with_args(env!("CARGO_BIN_EXE_patch_notes"), &["--timeout", "soon"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("Invalid --timeout"));
+
+    let output = api.run_with_args(env!("CARGO_BIN_EXE_patch_notes"), &["--proxy", "::"]);
+    assert!(!output.status.success());
+
+    assert!(api.requests().is_empty());
+}
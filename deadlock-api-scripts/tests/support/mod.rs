@@ -0,0 +1,200 @@
+//! Local stand-in for the Deadlock API serving recorded fixtures
+//!
+//! Fixtures live in `tests/fixtures`, one file per endpoint path
+//! (`/v1/players/mmr` -> `v1_players_mmr.json`); query strings are ignored.
+//! With `DEADLOCK_API_RECORD=1` requests are forwarded to the real API and
+//! successful responses are written back to the fixture files.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+use tiny_http::{Header, Response, Server};
+
+const UPSTREAM_URL: &str = "https://api.deadlock-api.com";
+const RECORD_ENV: &str = "DEADLOCK_API_RECORD";
+const BASE_URL_ENV: &str = "DEADLOCK_API_BASE_URL";
+
+type Overrides = Arc<Mutex<HashMap<String, (u16, String)>>>;
+
+/// Request received by the mock server
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    /// Path and query
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+}
+
+impl RecordedRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+pub struct MockApi {
+    base_url: String,
+    server: Arc<Server>,
+    overrides: Overrides,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MockApi {
+    /// Starts the server on a free local port
+    pub fn start() -> Self {
+        let server = Arc::new(Server::http("127.0.0.1:0").expect("failed to bind mock API"));
+        let port = server
+            .server_addr()
+            .to_ip()
+            .expect("mock API is not bound to an IP address")
+            .port();
+
+        let overrides: Overrides = Arc::default();
+        let requests: Arc<Mutex<Vec<RecordedRequest>>> = Arc::default();
+        let record = std::env::var(RECORD_ENV).is_ok_and(|value| value == "1");
+
+        let handle = {
+            let server = Arc::clone(&server);
+            let overrides = Arc::clone(&overrides);
+            let requests = Arc::clone(&requests);
+            std::thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    let url = request.url().to_string();
+                    let path = url.split('?').next().unwrap_or_default().to_string();
+                    let headers = request
+                        .headers()
+                        .iter()
+                        .map(|h| (h.field.to_string(), h.value.to_string()))
+                        .collect();
+                    requests.lock().unwrap().push(RecordedRequest {
+                        url: url.clone(),
+                        headers,
+                    });
+
+                    let overridden = overrides.lock().unwrap().get(&path).cloned();
+                    let (status, body) = match overridden {
+                        Some(response) => response,
+                        None if record => record_fixture(&path, &url),
+                        None => replay_fixture(&path),
+                    };
+
+                    let content_type = Header::from_bytes("Content-Type", "application/json")
+                        .expect("valid header");
+                    let response = Response::from_string(body)
+                        .with_status_code(status)
+                        .with_header(content_type);
+                    let _ = request.respond(response);
+                }
+            })
+        };
+
+        Self {
+            base_url: format!("http://127.0.0.1:{}", port),
+            server,
+            overrides,
+            requests,
+            handle: Some(handle),
+        }
+    }
+
+    /// Serves `status` with `body` for `path` instead of the fixture
+    pub fn respond_with(&self, path: &str, status: u16, body: &str) {
+        self.overrides
+            .lock()
+            .unwrap()
+            .insert(path.to_string(), (status, body.to_string()));
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Request URLs (path and query) received so far
+    pub fn requests(&self) -> Vec<String> {
+        self.recorded()
+            .into_iter()
+            .map(|request| request.url)
+            .collect()
+    }
+
+    /// Requests with headers received so far
+    pub fn recorded(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Runs a script binary against this server (base URL from the environment)
+    pub fn run(&self, bin: &str) -> Output {
+        Command::new(bin)
+            .env(BASE_URL_ENV, &self.base_url)
+            // Keep system proxies away from the local server
+            .env("NO_PROXY", "127.0.0.1")
+            .output()
+            .expect("failed to run script")
+    }
+
+    /// Runs a script binary with command line flags only
+    pub fn run_with_args(&self, bin: &str, args: &[&str]) -> Output {
+        Command::new(bin)
+            .args(args)
+            .env_remove(BASE_URL_ENV)
+            .env_remove("DEADLOCK_API_KEY")
+            .env("NO_PROXY", "127.0.0.1")
+            .output()
+            .expect("failed to run script")
+    }
+}
+
+impl Drop for MockApi {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn fixture_path(path: &str) -> PathBuf {
+    let name = path.trim_matches('/').replace(['/', '-'], "_");
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(format!("{}.json", name))
+}
+
+fn replay_fixture(path: &str) -> (u16, String) {
+    match fs::read_to_string(fixture_path(path)) {
+        Ok(body) => (200, body),
+        Err(_) => (404, format!(r#"{{"error":"no fixture for {}"}}"#, path)),
+    }
+}
+
+/// Forwards the request to the real API and stores a successful response
+fn record_fixture(path: &str, url: &str) -> (u16, String) {
+    let response = match reqwest::blocking::get(format!("{}{}", UPSTREAM_URL, url)) {
+        Ok(response) => response,
+        Err(e) => {
+            return (
+                502,
+                format!(r#"{{"error":"upstream request failed: {}"}}"#, e),
+            )
+        }
+    };
+    let status = response.status().as_u16();
+    let body = response.text().unwrap_or_default();
+
+    if status == 200 {
+        // Pretty-print so fixture diffs stay readable
+        let pretty = serde_json::from_str::<serde_json::Value>(&body)
+            .and_then(|value| serde_json::to_string_pretty(&value))
+            .unwrap_or_else(|_| body.clone());
+        fs::write(fixture_path(path), pretty + "\n").expect("failed to write fixture");
+    }
+
+    (status, body)
+}
@@ -1,3 +1,74 @@
+#[path = "build/bindings.rs"]
+mod bindings;
+#[path = "build/openapi.rs"]
+mod openapi;
+
+use std::path::Path;
+
+const OPENAPI_SPEC: &str = "openapi/deadlock-api.json";
+
+/// TypeScript bindings consumed by the frontend
+const BINDINGS_FILE: &str = "../src/bindings.ts";
+
+/// Set to write the generated bindings to [`BINDINGS_FILE`] instead of
+/// failing the build when they differ
+const UPDATE_BINDINGS_ENV: &str = "DEADLYZE_UPDATE_BINDINGS";
+
+/// Rust sources under `dir`, sorted by path
+fn collect_sources(dir: &Path, sources: &mut Vec<bindings::Source>) {
+    let mut entries: Vec<_> = std::fs::read_dir(dir)
+        .expect("failed to read sources")
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    entries.sort();
+
+    for path in entries {
+        if path.is_dir() {
+            collect_sources(&path, sources);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            sources.push(bindings::Source {
+                path: path.display().to_string(),
+                code: std::fs::read_to_string(&path).expect("failed to read source"),
+            });
+        }
+    }
+}
+
 fn main() {
+    println!("cargo:rerun-if-changed={}", OPENAPI_SPEC);
+    println!("cargo:rerun-if-changed=build");
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed={}", BINDINGS_FILE);
+    println!("cargo:rerun-if-env-changed={}", UPDATE_BINDINGS_ENV);
+
+    let spec = std::fs::read_to_string(OPENAPI_SPEC).expect("failed to read OpenAPI spec");
+    let spec: serde_json::Value = serde_json::from_str(&spec).expect("invalid OpenAPI spec");
+    let code = openapi::generate(&spec).expect("failed to generate API client");
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    let out_dir = Path::new(&out_dir);
+    std::fs::write(out_dir.join("deadlock_api.rs"), &code)
+        .expect("failed to write generated API client");
+
+    let mut sources = vec![bindings::Source {
+        path: "deadlock_api.rs".into(),
+        code,
+    }];
+    collect_sources(Path::new("src"), &mut sources);
+    let bindings = bindings::generate(&sources).unwrap_or_else(|e| panic!("{}", e));
+
+    // A stale frontend file would only surface as runtime errors in the UI
+    let current = std::fs::read_to_string(BINDINGS_FILE).unwrap_or_default();
+    if current.replace("\r\n", "\n") != bindings {
+        if std::env::var_os(UPDATE_BINDINGS_ENV).is_some() {
+            std::fs::write(BINDINGS_FILE, &bindings).expect("failed to write TypeScript bindings");
+        } else {
+            panic!(
+                "{} is out of date, rebuild with {}=1 to update it",
+                BINDINGS_FILE, UPDATE_BINDINGS_ENV
+            );
+        }
+    }
+
     tauri_build::build()
 }
@@ -0,0 +1,922 @@
+//! TypeScript bindings for the frontend (`src/bindings.ts`)
+//!
+//! Scans the app sources for `#[tauri::command]` functions and `Event` impls
+//! and emits a TypeScript type for every serde type reachable from them, a
+//! typed wrapper per command and a name-to-payload map of events. Only the
+//! serde attributes used in this crate are supported; anything else fails the
+//! build instead of producing wrong types.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use syn::punctuated::Punctuated;
+use syn::{
+    Attribute, Expr, Fields, FnArg, GenericArgument, ImplItem, Item, ItemEnum, ItemFn, ItemStruct,
+    ItemType, Lit, LitStr, Meta, Pat, PathArguments, ReturnType, Token, Type,
+};
+
+/// Types with hand-written serde impls
+const MANUAL_TYPES: &[(&str, &str)] = &[(
+    "AppError",
+    "{\n  code: string;\n  key: string;\n  message: string;\n  context: Record<string, string | number>;\n}",
+)];
+
+/// Command parameters injected by Tauri (not sent by the frontend)
+const INJECTED_PARAMS: &[&str] = &["AppHandle", "State", "Window", "WebviewWindow", "Webview"];
+
+/// Rust source file
+pub struct Source {
+    pub path: String,
+    pub code: String,
+}
+
+struct Command {
+    name: String,
+    docs: Vec<String>,
+    params: Vec<(String, Type)>,
+    output: Option<Type>,
+}
+
+struct EventDef {
+    name: String,
+    payload: String,
+}
+
+enum Definition {
+    Struct(ItemStruct),
+    Enum(ItemEnum),
+    Alias(ItemType),
+}
+
+#[derive(Default)]
+struct Collected {
+    commands: Vec<Command>,
+    events: Vec<EventDef>,
+    /// Serde types by name with the source file defining them
+    definitions: BTreeMap<String, (String, Definition)>,
+}
+
+impl Collected {
+    /// Adds a type; TypeScript has one namespace, so a name may be defined once
+    fn define(&mut self, path: &str, name: String, definition: Definition) -> Result<(), String> {
+        if let Some((first, _)) = self.definitions.get(&name) {
+            return Err(format!("type {} is also defined in {}", name, first));
+        }
+        self.definitions
+            .insert(name, (path.to_string(), definition));
+        Ok(())
+    }
+}
+
+/// serde attributes of a container, field or variant
+#[derive(Default)]
+struct SerdeAttrs {
+    rename: Option<String>,
+    rename_all: Option<String>,
+    tag: Option<String>,
+    untagged: bool,
+    skip: bool,
+    optional: bool,
+    flatten: bool,
+}
+
+impl SerdeAttrs {
+    fn parse(attrs: &[Attribute]) -> Result<Self, String> {
+        let mut result = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+            attr.parse_nested_meta(|meta| {
+                let name = meta
+                    .path
+                    .get_ident()
+                    .map(ToString::to_string)
+                    .unwrap_or_default();
+                match name.as_str() {
+                    "rename" => result.rename = Some(meta.value()?.parse::<LitStr>()?.value()),
+                    "rename_all" => {
+                        result.rename_all = Some(meta.value()?.parse::<LitStr>()?.value())
+                    }
+                    "tag" => result.tag = Some(meta.value()?.parse::<LitStr>()?.value()),
+                    "untagged" => result.untagged = true,
+                    "skip" => result.skip = true,
+                    "flatten" => result.flatten = true,
+                    "skip_serializing_if" => {
+                        meta.value()?.parse::<LitStr>()?;
+                        result.optional = true;
+                    }
+                    // Deserialization-only attributes (`with` may change the
+                    // representation, so it isn't accepted)
+                    "default" | "alias" | "deny_unknown_fields" => {
+                        if meta.input.peek(Token![=]) {
+                            meta.value()?.parse::<LitStr>()?;
+                        }
+                    }
+                    _ => return Err(meta.error(format!("unsupported serde attribute `{}`", name))),
+                }
+                Ok(())
+            })
+            .map_err(|e| e.to_string())?;
+        }
+        Ok(result)
+    }
+}
+
+/// Doc comment lines of an item
+fn docs(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(meta) => match &meta.value {
+                Expr::Lit(expr) => match &expr.lit {
+                    Lit::Str(text) => Some(text.value().trim().to_string()),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+fn js_doc(out: &mut String, indent: &str, lines: &[String]) {
+    match lines {
+        [] => {}
+        [line] => writeln!(out, "{}/** {} */", indent, line).unwrap(),
+        lines => {
+            writeln!(out, "{}/**", indent).unwrap();
+            for line in lines {
+                writeln!(
+                    out,
+                    "{} *{}{}",
+                    indent,
+                    if line.is_empty() { "" } else { " " },
+                    line
+                )
+                .unwrap();
+            }
+            writeln!(out, "{} */", indent).unwrap();
+        }
+    }
+}
+
+fn has_derive(attrs: &[Attribute], names: &[&str]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .any(|attr| {
+            attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+                .is_ok_and(|paths| {
+                    paths.iter().any(|path| {
+                        path.segments.last().is_some_and(|segment| {
+                            names.contains(&segment.ident.to_string().as_str())
+                        })
+                    })
+                })
+        })
+}
+
+fn is_test_module(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("cfg")
+            && attr
+                .parse_args::<syn::Ident>()
+                .is_ok_and(|ident| ident == "test")
+    })
+}
+
+fn is_command(function: &ItemFn) -> bool {
+    function.attrs.iter().any(|attr| {
+        let segments: Vec<String> = attr
+            .path()
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect();
+        segments == ["tauri", "command"]
+    })
+}
+
+/// Last path segment of a type (`tauri::State<'_, T>` -> `State`)
+fn type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string()),
+        Type::Reference(reference) => type_name(&reference.elem),
+        _ => None,
+    }
+}
+
+fn collect_items(items: &[Item], path: &str, collected: &mut Collected) -> Result<(), String> {
+    for item in items {
+        match item {
+            Item::Fn(function) if is_command(function) => {
+                let mut params = Vec::new();
+                for input in &function.sig.inputs {
+                    let FnArg::Typed(arg) = input else {
+                        return Err(format!("command {} takes self", function.sig.ident));
+                    };
+                    if type_name(&arg.ty)
+                        .is_some_and(|name| INJECTED_PARAMS.contains(&name.as_str()))
+                    {
+                        continue;
+                    }
+                    let Pat::Ident(name) = &*arg.pat else {
+                        return Err(format!(
+                            "command {} has a pattern argument",
+                            function.sig.ident
+                        ));
+                    };
+                    params.push((name.ident.to_string(), (*arg.ty).clone()));
+                }
+
+                collected.commands.push(Command {
+                    name: function.sig.ident.to_string(),
+                    docs: docs(&function.attrs),
+                    params,
+                    output: match &function.sig.output {
+                        ReturnType::Default => None,
+                        ReturnType::Type(_, ty) => Some((**ty).clone()),
+                    },
+                });
+            }
+            Item::Struct(item) if has_derive(&item.attrs, &["Serialize", "Deserialize"]) => {
+                collected.define(
+                    path,
+                    item.ident.to_string(),
+                    Definition::Struct(item.clone()),
+                )?;
+            }
+            Item::Enum(item) if has_derive(&item.attrs, &["Serialize", "Deserialize"]) => {
+                collected.define(path, item.ident.to_string(), Definition::Enum(item.clone()))?;
+            }
+            Item::Type(item) if item.generics.params.is_empty() => {
+                collected.define(
+                    path,
+                    item.ident.to_string(),
+                    Definition::Alias(item.clone()),
+                )?;
+            }
+            Item::Impl(item) => {
+                let is_event = item
+                    .trait_
+                    .as_ref()
+                    .and_then(|(_, path, _)| path.segments.last())
+                    .is_some_and(|segment| segment.ident == "Event");
+                if !is_event {
+                    continue;
+                }
+                let payload = type_name(&item.self_ty).ok_or("unsupported Event impl")?;
+                let name = item.items.iter().find_map(|impl_item| match impl_item {
+                    ImplItem::Const(constant) if constant.ident == "NAME" => match &constant.expr {
+                        Expr::Lit(expr) => match &expr.lit {
+                            Lit::Str(text) => Some(text.value()),
+                            _ => None,
+                        },
+                        _ => None,
+                    },
+                    _ => None,
+                });
+                let name =
+                    name.ok_or_else(|| format!("Event impl for {} needs a literal NAME", payload))?;
+                collected.events.push(EventDef { name, payload });
+            }
+            Item::Mod(module) if !is_test_module(&module.attrs) => {
+                if let Some((_, items)) = &module.content {
+                    collect_items(items, path, collected)?;
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Splits an identifier into lowercase words (`PlayerMMR` -> `player`, `mmr`)
+fn words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for part in name.split('_').filter(|part| !part.is_empty()) {
+        let chars: Vec<char> = part.chars().collect();
+        let mut word = String::new();
+        for (i, &ch) in chars.iter().enumerate() {
+            let boundary = ch.is_uppercase()
+                && i > 0
+                && (chars[i - 1].is_lowercase()
+                    || chars.get(i + 1).is_some_and(|next| next.is_lowercase()));
+            if boundary && !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            word.extend(ch.to_lowercase());
+        }
+        words.push(word);
+    }
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// Applies `rename_all` to a field or variant name
+fn rename(name: &str, rule: Option<&str>) -> Result<String, String> {
+    let words = words(name);
+    Ok(match rule {
+        None => name.to_string(),
+        Some("lowercase") => words.concat(),
+        Some("UPPERCASE") => words.concat().to_uppercase(),
+        Some("snake_case") => words.join("_"),
+        Some("SCREAMING_SNAKE_CASE") => words.join("_").to_uppercase(),
+        Some("kebab-case") => words.join("-"),
+        Some("PascalCase") => words.iter().map(|word| capitalize(word)).collect(),
+        Some("camelCase") => {
+            let pascal: String = words.iter().map(|word| capitalize(word)).collect();
+            let mut chars = pascal.chars();
+            chars
+                .next()
+                .map(|first| first.to_lowercase().chain(chars).collect())
+                .unwrap_or_default()
+        }
+        Some(rule) => return Err(format!("unsupported rename_all rule {}", rule)),
+    })
+}
+
+/// Property key, quoted when not a valid identifier
+fn property(name: &str) -> String {
+    if name
+        .chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '$')
+        && !name.starts_with(|ch: char| ch.is_ascii_digit())
+    {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name)
+    }
+}
+
+fn generic_args(args: &PathArguments) -> Vec<&Type> {
+    match args {
+        PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Wraps union types before appending `[]`
+fn array_of(element: String) -> String {
+    if element.contains(' ') {
+        format!("({})[]", element)
+    } else {
+        format!("{}[]", element)
+    }
+}
+
+/// TypeScript type of a Rust type; referenced named types are added to `refs`
+fn ts_type(ty: &Type, refs: &mut BTreeSet<String>) -> Result<String, String> {
+    match ty {
+        Type::Reference(reference) => ts_type(&reference.elem, refs),
+        Type::Paren(paren) => ts_type(&paren.elem, refs),
+        Type::Slice(slice) => Ok(array_of(ts_type(&slice.elem, refs)?)),
+        Type::Array(array) => Ok(array_of(ts_type(&array.elem, refs)?)),
+        Type::Tuple(tuple) if tuple.elems.is_empty() => Ok("null".into()),
+        Type::Tuple(tuple) => {
+            let elements = tuple
+                .elems
+                .iter()
+                .map(|elem| ts_type(elem, refs))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(format!("[{}]", elements.join(", ")))
+        }
+        Type::Path(path) => {
+            let segment = path.path.segments.last().ok_or("empty type path")?;
+            let name = segment.ident.to_string();
+            let args = generic_args(&segment.arguments);
+            let arg = |index: usize, refs: &mut BTreeSet<String>| {
+                args.get(index)
+                    .ok_or_else(|| format!("{} without type argument", name))
+                    .and_then(|ty| ts_type(ty, refs))
+            };
+
+            Ok(match name.as_str() {
+                "String" | "str" | "char" | "PathBuf" | "Path" | "DateTime" | "NaiveDate" => {
+                    "string".into()
+                }
+                "u8" | "u16" | "u32" | "u64" | "usize" | "i8" | "i16" | "i32" | "i64" | "isize"
+                | "f32" | "f64" => "number".into(),
+                "bool" => "boolean".into(),
+                "Value" => "unknown".into(),
+                "Option" => format!("{} | null", arg(0, refs)?),
+                "Vec" | "VecDeque" | "HashSet" | "BTreeSet" => array_of(arg(0, refs)?),
+                "HashMap" | "BTreeMap" => {
+                    format!("Partial<Record<{}, {}>>", arg(0, refs)?, arg(1, refs)?)
+                }
+                "Box" | "Arc" | "Rc" | "Result" => arg(0, refs)?,
+                _ if args.is_empty() => {
+                    refs.insert(name.clone());
+                    name
+                }
+                _ => return Err(format!("unsupported generic type {}", name)),
+            })
+        }
+        _ => Err("unsupported type".into()),
+    }
+}
+
+fn is_option(ty: &Type) -> bool {
+    type_name(ty).is_some_and(|name| name == "Option")
+}
+
+/// Object members of named fields; flattened fields are returned separately
+fn object_fields(
+    fields: &syn::FieldsNamed,
+    rename_all: Option<&str>,
+    refs: &mut BTreeSet<String>,
+) -> Result<(String, Vec<String>), String> {
+    let mut body = String::new();
+    let mut flattened = Vec::new();
+
+    for field in &fields.named {
+        let attrs = SerdeAttrs::parse(&field.attrs)?;
+        if attrs.skip {
+            continue;
+        }
+        let ident = field.ident.as_ref().unwrap().to_string();
+        let ident = ident.trim_start_matches("r#");
+        let ty = ts_type(&field.ty, refs).map_err(|e| format!("field {}: {}", ident, e))?;
+        if attrs.flatten {
+            flattened.push(ty);
+            continue;
+        }
+
+        let name = match attrs.rename {
+            Some(name) => name,
+            None => rename(ident, rename_all)?,
+        };
+        let optional = if attrs.optional || is_option(&field.ty) {
+            "?"
+        } else {
+            ""
+        };
+        js_doc(&mut body, "  ", &docs(&field.attrs));
+        writeln!(body, "  {}{}: {};", property(&name), optional, ty).unwrap();
+    }
+
+    Ok((body, flattened))
+}
+
+/// Object type from members and flattened types (`A & { ... }`)
+fn object_type(body: String, flattened: Vec<String>) -> String {
+    let object = format!("{{\n{}}}", body);
+    if flattened.is_empty() {
+        object
+    } else if body.is_empty() {
+        flattened.join(" & ")
+    } else {
+        format!("{} & {}", flattened.join(" & "), object)
+    }
+}
+
+fn struct_type(item: &ItemStruct, refs: &mut BTreeSet<String>) -> Result<String, String> {
+    let attrs = SerdeAttrs::parse(&item.attrs)?;
+    match &item.fields {
+        Fields::Named(fields) => {
+            let (body, flattened) = object_fields(fields, attrs.rename_all.as_deref(), refs)?;
+            Ok(object_type(body, flattened))
+        }
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            ts_type(&fields.unnamed[0].ty, refs)
+        }
+        Fields::Unnamed(fields) => {
+            let elements = fields
+                .unnamed
+                .iter()
+                .map(|field| ts_type(&field.ty, refs))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(format!("[{}]", elements.join(", ")))
+        }
+        Fields::Unit => Ok("null".into()),
+    }
+}
+
+fn enum_type(item: &ItemEnum, refs: &mut BTreeSet<String>) -> Result<String, String> {
+    let attrs = SerdeAttrs::parse(&item.attrs)?;
+    let mut variants = Vec::new();
+
+    for variant in &item.variants {
+        let variant_attrs = SerdeAttrs::parse(&variant.attrs)?;
+        if variant_attrs.skip {
+            continue;
+        }
+        let name = match variant_attrs.rename {
+            Some(name) => name,
+            None => rename(&variant.ident.to_string(), attrs.rename_all.as_deref())?,
+        };
+        let literal = format!("\"{}\"", name);
+
+        let ts = match (&variant.fields, &attrs.tag, attrs.untagged) {
+            (Fields::Unit, None, false) => literal,
+            (Fields::Unit, None, true) => "null".into(),
+            (Fields::Unit, Some(tag), _) => format!("{{ {}: {} }}", property(tag), literal),
+            (Fields::Named(fields), tag, untagged) => {
+                let (body, flattened) = object_fields(fields, None, refs)?;
+                match (tag, untagged) {
+                    (Some(tag), _) => {
+                        let body = format!("  {}: {};\n{}", property(tag), literal, body);
+                        object_type(body, flattened)
+                    }
+                    (None, true) => object_type(body, flattened),
+                    (None, false) => format!(
+                        "{{ {}: {} }}",
+                        property(&name),
+                        object_type(body, flattened)
+                    ),
+                }
+            }
+            (Fields::Unnamed(fields), tag, untagged) if fields.unnamed.len() == 1 => {
+                let inner = ts_type(&fields.unnamed[0].ty, refs)?;
+                match (tag, untagged) {
+                    (Some(tag), _) => format!("{{ {}: {} }} & {}", property(tag), literal, inner),
+                    (None, true) => inner,
+                    (None, false) => format!("{{ {}: {} }}", property(&name), inner),
+                }
+            }
+            _ => return Err(format!("unsupported variant {}", variant.ident)),
+        };
+        variants.push(ts);
+    }
+
+    if variants.is_empty() {
+        return Ok("never".into());
+    }
+    if variants.iter().all(|variant| !variant.contains('\n')) {
+        return Ok(variants.join(" | "));
+    }
+    // One variant per line (`\n  | { ... }`)
+    Ok(variants
+        .iter()
+        .map(|variant| format!("\n  | {}", variant.replace('\n', "\n    ")))
+        .collect())
+}
+
+/// `get_match_history` -> `getMatchHistory`
+fn camel_case(name: &str) -> String {
+    rename(name, Some("camelCase")).unwrap()
+}
+
+fn generate_command(
+    out: &mut String,
+    command: &Command,
+    refs: &mut BTreeSet<String>,
+) -> Result<(), String> {
+    let context = |e: String| format!("command {}: {}", command.name, e);
+
+    // Trailing `Option` parameters may be omitted
+    let optional_from = command
+        .params
+        .iter()
+        .rposition(|(_, ty)| !is_option(ty))
+        .map_or(0, |index| index + 1);
+
+    let mut params = Vec::new();
+    let mut args = Vec::new();
+    for (index, (name, ty)) in command.params.iter().enumerate() {
+        let name = camel_case(name);
+        let optional = if index >= optional_from { "?" } else { "" };
+        params.push(format!(
+            "{}{}: {}",
+            name,
+            optional,
+            ts_type(ty, refs).map_err(context)?
+        ));
+        args.push(name);
+    }
+
+    let output = match &command.output {
+        None => "void".to_string(),
+        Some(ty) => match ts_type(ty, refs).map_err(context)?.as_str() {
+            "null" => "void".to_string(),
+            output => output.to_string(),
+        },
+    };
+
+    js_doc(out, "", &command.docs);
+    let name = camel_case(&command.name);
+    let signature = format!(
+        "export function {}({}): Promise<{}> {{",
+        name,
+        params.join(", "),
+        output
+    );
+    if signature.len() <= 100 {
+        writeln!(out, "{}", signature).unwrap();
+    } else {
+        writeln!(out, "export function {}(", name).unwrap();
+        for param in &params {
+            writeln!(out, "  {},", param).unwrap();
+        }
+        writeln!(out, "): Promise<{}> {{", output).unwrap();
+    }
+    if args.is_empty() {
+        writeln!(out, "  return invoke(\"{}\");", command.name).unwrap();
+    } else {
+        writeln!(
+            out,
+            "  return invoke(\"{}\", {{ {} }});",
+            command.name,
+            args.join(", ")
+        )
+        .unwrap();
+    }
+    out.push_str("}\n\n");
+    Ok(())
+}
+
+/// Generates TypeScript bindings for the given sources
+pub fn generate(sources: &[Source]) -> Result<String, String> {
+    let mut collected = Collected::default();
+    for source in sources {
+        let file = syn::parse_file(&source.code).map_err(|e| format!("{}: {}", source.path, e))?;
+        collect_items(&file.items, &source.path, &mut collected)
+            .map_err(|e| format!("{}: {}", source.path, e))?;
+    }
+    collected.commands.sort_by(|a, b| a.name.cmp(&b.name));
+    collected.events.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut refs = BTreeSet::new();
+    let mut commands = String::new();
+    for command in &collected.commands {
+        generate_command(&mut commands, command, &mut refs)?;
+    }
+    refs.extend(collected.events.iter().map(|event| event.payload.clone()));
+    refs.extend(MANUAL_TYPES.iter().map(|(name, _)| name.to_string()));
+
+    // Emit every type reachable from commands and events
+    let mut types = BTreeMap::new();
+    let mut pending: Vec<String> = refs.iter().cloned().collect();
+    while let Some(name) = pending.pop() {
+        if types.contains_key(&name) {
+            continue;
+        }
+
+        let mut nested = BTreeSet::new();
+        let manual = MANUAL_TYPES.iter().find(|(manual, _)| *manual == name);
+        let definition = collected
+            .definitions
+            .get(&name)
+            .map(|(_, definition)| definition);
+        let (comment, interface, ts) = match (manual, definition) {
+            (Some((_, ts)), _) => (Vec::new(), true, Ok(ts.to_string())),
+            (None, Some(Definition::Struct(item))) => (
+                docs(&item.attrs),
+                matches!(item.fields, Fields::Named(_)),
+                struct_type(item, &mut nested),
+            ),
+            (None, Some(Definition::Enum(item))) => {
+                (docs(&item.attrs), false, enum_type(item, &mut nested))
+            }
+            (None, Some(Definition::Alias(item))) => {
+                (docs(&item.attrs), false, ts_type(&item.ty, &mut nested))
+            }
+            (None, None) => return Err(format!("type {} is not serializable or not found", name)),
+        };
+        let ts = ts.map_err(|e| format!("type {}: {}", name, e))?;
+        // Flattened structs become intersection types
+        let interface = interface && ts.starts_with('{');
+
+        pending.extend(
+            nested
+                .into_iter()
+                .filter(|nested| !types.contains_key(nested)),
+        );
+        types.insert(name, (comment, interface, ts));
+    }
+
+    let mut out = String::new();
+    out.push_str(
+        "// Generated by src-tauri/build/bindings.rs from the backend sources. Do not edit.\n\n",
+    );
+    out.push_str("import { invoke } from \"@tauri-apps/api/core\";\n");
+    out.push_str(
+        "import { listen, type EventCallback, type UnlistenFn } from \"@tauri-apps/api/event\";\n\n",
+    );
+
+    for (name, (comment, interface, ts)) in &types {
+        js_doc(&mut out, "", comment);
+        if *interface {
+            writeln!(out, "export interface {} {}\n", name, ts).unwrap();
+        } else if ts.starts_with("\n") {
+            writeln!(out, "export type {} ={};\n", name, ts).unwrap();
+        } else {
+            writeln!(out, "export type {} = {};\n", name, ts).unwrap();
+        }
+    }
+
+    out.push_str(&commands);
+
+    out.push_str("/** Payload of every event emitted by the backend */\n");
+    out.push_str("export interface Events {\n");
+    for event in &collected.events {
+        writeln!(out, "  \"{}\": {};", event.name, event.payload).unwrap();
+    }
+    out.push_str("}\n\n");
+    out.push_str(
+        "export function listenEvent<E extends keyof Events>(\n  event: E,\n  handler: EventCallback<Events[E]>,\n): Promise<UnlistenFn> {\n  return listen(event, handler);\n}\n",
+    );
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bindings(code: &str) -> Result<String, String> {
+        generate(&[Source {
+            path: "src/lib.rs".into(),
+            code: code.into(),
+        }])
+    }
+
+    #[test]
+    fn renames_fields() {
+        let ts = bindings(
+            r#"
+            #[derive(Serialize)]
+            #[serde(rename_all = "camelCase")]
+            pub struct Player {
+                pub player_name: String,
+                #[serde(rename = "id")]
+                pub account_id: u32,
+                pub r#type: u8,
+            }
+
+            #[tauri::command]
+            pub fn get_player() -> Player {}
+            "#,
+        )
+        .unwrap();
+
+        assert!(ts.contains(
+            "export interface Player {\n  playerName: string;\n  id: number;\n  type: number;\n}\n"
+        ));
+    }
+
+    #[test]
+    fn maps_tagged_and_untagged_enums() {
+        let ts = bindings(
+            r#"
+            #[derive(Serialize)]
+            #[serde(tag = "type", rename_all = "snake_case")]
+            pub enum Period {
+                LastDays { days: u32 },
+                AllTime,
+            }
+
+            #[derive(Serialize)]
+            #[serde(untagged)]
+            pub enum Id {
+                Number(u32),
+                Name(String),
+            }
+
+            #[derive(Serialize)]
+            #[serde(rename_all = "lowercase")]
+            pub enum Tier {
+                High,
+                Low,
+            }
+
+            #[tauri::command]
+            pub fn get(period: Period, id: Id) -> Tier {}
+            "#,
+        )
+        .unwrap();
+
+        assert!(ts.contains(
+            "export type Period =\n  | {\n      type: \"last_days\";\n      days: number;\n    }\n  | { type: \"all_time\" };\n"
+        ));
+        assert!(ts.contains("export type Id = number | string;\n"));
+        assert!(ts.contains("export type Tier = \"high\" | \"low\";\n"));
+    }
+
+    #[test]
+    fn flattens_into_intersection_types() {
+        let ts = bindings(
+            r#"
+            #[derive(Serialize)]
+            pub struct Details {
+                pub match_id: u64,
+            }
+
+            #[derive(Serialize)]
+            pub struct Entry {
+                #[serde(flatten)]
+                pub details: Details,
+                pub won: bool,
+            }
+
+            #[tauri::command]
+            pub fn get_entry() -> Entry {}
+            "#,
+        )
+        .unwrap();
+
+        assert!(ts.contains("export type Entry = Details & {\n  won: boolean;\n};\n"));
+        assert!(ts.contains("export interface Details {\n  match_id: number;\n}\n"));
+    }
+
+    #[test]
+    fn makes_only_trailing_options_optional() {
+        let ts = bindings(
+            r#"
+            #[tauri::command]
+            pub async fn get_mates(
+                api: State<'_, ApiClient>,
+                before: Option<u32>,
+                account_id: u32,
+                min_games: Option<u64>,
+            ) -> Result<Vec<u32>, AppError> {}
+            "#,
+        )
+        .unwrap();
+
+        assert!(ts.contains(concat!(
+            "export function getMates(\n",
+            "  before: number | null,\n",
+            "  accountId: number,\n",
+            "  minGames?: number | null,\n",
+            "): Promise<number[]> {\n",
+            "  return invoke(\"get_mates\", { before, accountId, minGames });\n",
+            "}\n",
+        )));
+    }
+
+    #[test]
+    fn rejects_duplicate_type_names() {
+        let error = generate(&[
+            Source {
+                path: "src/a.rs".into(),
+                code: "#[derive(Serialize)] pub struct Entry { pub id: u32 }".into(),
+            },
+            Source {
+                path: "src/b.rs".into(),
+                code: "#[derive(Deserialize)] pub struct Entry { pub name: String }".into(),
+            },
+        ])
+        .unwrap_err();
+
+        assert_eq!(error, "src/b.rs: type Entry is also defined in src/a.rs");
+    }
+
+    #[test]
+    fn rejects_unsupported_serde_attributes() {
+        let error = bindings(
+            r#"
+            #[derive(Serialize)]
+            #[serde(transparent)]
+            pub struct Id(u32);
+
+            #[tauri::command]
+            pub fn get_id() -> Id {}
+            "#,
+        )
+        .unwrap_err();
+
+        assert!(
+            error.contains("unsupported serde attribute `transparent`"),
+            "{}",
+            error
+        );
+
+        let error = bindings(
+            r#"
+            #[derive(Serialize)]
+            pub struct Stamp {
+                #[serde(with = "chrono::serde::ts_seconds")]
+                pub at: DateTime<Utc>,
+            }
+
+            #[tauri::command]
+            pub fn get_stamp() -> Stamp {}
+            "#,
+        )
+        .unwrap_err();
+
+        assert!(
+            error.contains("unsupported serde attribute `with`"),
+            "{}",
+            error
+        );
+    }
+}
@@ -0,0 +1,434 @@
+//! Code generation from the vendored OpenAPI spec (`openapi/deadlock-api.json`)
+//!
+//! Generates a model struct per object schema, a params struct per operation
+//! with query parameters and an `ApiClient` method per GET operation. Only the
+//! subset of OpenAPI used by the Deadlock API spec is supported.
+
+use std::fmt::Write;
+
+use serde_json::{Map, Value};
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "crate", "dyn", "else", "enum",
+    "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+    "mut", "pub", "ref", "return", "static", "struct", "trait", "true", "type", "unsafe", "use",
+    "where", "while",
+];
+
+fn field_name(name: &str) -> String {
+    if RUST_KEYWORDS.contains(&name) {
+        format!("r#{}", name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// `get_steam_profiles` -> `GetSteamProfiles`
+fn pascal_case(name: &str) -> String {
+    name.split(['_', '-'])
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            let first = chars.next().unwrap().to_ascii_uppercase();
+            std::iter::once(first).chain(chars).collect::<String>()
+        })
+        .collect()
+}
+
+fn doc(out: &mut String, indent: &str, text: Option<&str>) {
+    if let Some(text) = text {
+        for line in text.lines() {
+            writeln!(out, "{}/// {}", indent, line.trim_end()).unwrap();
+        }
+    }
+}
+
+/// Single alternative of `oneOf`/`anyOf` with a `null` variant (OpenAPI 3.1
+/// form of an optional reference), with the `null` variant split off
+fn non_null(schema: &Value) -> (&Value, bool) {
+    for key in ["oneOf", "anyOf"] {
+        if let Some(variants) = schema[key].as_array() {
+            let (nulls, rest): (Vec<&Value>, Vec<&Value>) = variants
+                .iter()
+                .partition(|variant| variant["type"] == "null");
+            if let [variant] = rest[..] {
+                return (variant, !nulls.is_empty());
+            }
+        }
+    }
+    (schema, false)
+}
+
+/// Schema type with nullability (`["string", "null"]`) split off
+fn schema_type(schema: &Value) -> (Option<&str>, bool) {
+    let (schema, null_variant) = non_null(schema);
+    match &schema["type"] {
+        Value::String(name) => (
+            Some(name.as_str()),
+            null_variant || schema["nullable"] == true,
+        ),
+        Value::Array(types) => {
+            let nullable = types.iter().any(|t| t == "null");
+            let name = types
+                .iter()
+                .filter_map(Value::as_str)
+                .find(|t| *t != "null");
+            (name, nullable)
+        }
+        _ => (None, null_variant),
+    }
+}
+
+/// Rust type of a schema (without the `Option` wrapper for nullable types)
+fn rust_type(schema: &Value) -> Result<String, String> {
+    let (schema, _) = non_null(schema);
+    if let Some(reference) = schema["$ref"].as_str() {
+        return reference
+            .strip_prefix("#/components/schemas/")
+            .map(str::to_string)
+            .ok_or_else(|| format!("unsupported reference {}", reference));
+    }
+
+    let (name, _) = schema_type(schema);
+    let unsigned = schema["minimum"].as_f64().is_some_and(|min| min >= 0.0);
+
+    Ok(match (name, schema["format"].as_str()) {
+        (Some("integer"), Some("int32")) if unsigned => "u32".into(),
+        (Some("integer"), Some("int32")) => "i32".into(),
+        (Some("integer"), Some("uint32")) => "u32".into(),
+        (Some("integer"), Some("uint64")) => "u64".into(),
+        (Some("integer"), _) if unsigned => "u64".into(),
+        (Some("integer"), _) => "i64".into(),
+        (Some("number"), _) => "f64".into(),
+        (Some("boolean"), _) => "bool".into(),
+        (Some("string"), _) => "String".into(),
+        (Some("array"), _) => format!("Vec<{}>", rust_type(&schema["items"])?),
+        _ => "serde_json::Value".into(),
+    })
+}
+
+/// Field type: nullable or not required fields become `Option`
+fn field_type(schema: &Value, required: bool) -> Result<String, String> {
+    let ty = rust_type(schema)?;
+    let (_, nullable) = schema_type(schema);
+    Ok(if nullable || !required {
+        format!("Option<{}>", ty)
+    } else {
+        ty
+    })
+}
+
+/// Default value of an optional field is the Rust `Default` of its type
+fn is_zero_default(value: &Value) -> bool {
+    match value {
+        Value::Bool(value) => !value,
+        Value::Number(number) => number.as_f64() == Some(0.0),
+        Value::String(text) => text.is_empty(),
+        Value::Array(items) => items.is_empty(),
+        _ => false,
+    }
+}
+
+fn generate_model(out: &mut String, name: &str, schema: &Value) -> Result<(), String> {
+    let empty = Map::new();
+    let properties = schema["properties"].as_object().unwrap_or(&empty);
+    let required: Vec<&str> = schema["required"]
+        .as_array()
+        .map(|names| names.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+
+    doc(out, "", schema["description"].as_str());
+    // Required fields stay required: a missing ID must fail instead of becoming 0
+    out.push_str("#[derive(Debug, Clone, Default, Serialize, Deserialize)]\n");
+    writeln!(out, "pub struct {} {{", name).unwrap();
+    for (field, field_schema) in properties {
+        let context = |e: String| format!("{}.{}: {}", name, field, e);
+        doc(out, "    ", field_schema["description"].as_str());
+
+        let required = required.contains(&field.as_str());
+        let default = &field_schema["default"];
+        // Optional field with a default keeps its type instead of becoming `Option`
+        let ty = if !required && !default.is_null() && !schema_type(field_schema).1 {
+            if !is_zero_default(default) {
+                return Err(context(format!("unsupported default {}", default)));
+            }
+            out.push_str("    #[serde(default)]\n");
+            rust_type(field_schema).map_err(context)?
+        } else {
+            field_type(field_schema, required).map_err(context)?
+        };
+        writeln!(out, "    pub {}: {},", field_name(field), ty).unwrap();
+    }
+    out.push_str("}\n\n");
+    Ok(())
+}
+
+/// Query parameter value as sent on the wire (arrays are comma separated)
+fn query_value(access: &str, schema: &Value) -> String {
+    if schema_type(schema).0 == Some("array") {
+        format!(
+            "{}.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(\",\")",
+            access
+        )
+    } else {
+        format!("{}.to_string()", access)
+    }
+}
+
+fn generate_operation(
+    out: &mut String,
+    methods: &mut String,
+    path: &str,
+    operation: &Value,
+) -> Result<(), String> {
+    let operation_id = operation["operationId"]
+        .as_str()
+        .ok_or_else(|| format!("GET {}: missing operationId", path))?;
+    let params_name = format!("{}Params", pascal_case(operation_id));
+    let empty = Vec::new();
+    let parameters = operation["parameters"].as_array().unwrap_or(&empty);
+
+    let path_params: Vec<&Value> = parameters.iter().filter(|p| p["in"] == "path").collect();
+    let query_params: Vec<&Value> = parameters.iter().filter(|p| p["in"] == "query").collect();
+
+    if !query_params.is_empty() {
+        writeln!(out, "/// Query parameters of `GET {}`", path).unwrap();
+        out.push_str("#[derive(Debug, Clone, Default)]\n");
+        writeln!(out, "pub struct {} {{", params_name).unwrap();
+        for param in &query_params {
+            let name = param["name"].as_str().unwrap_or_default();
+            doc(out, "    ", param["description"].as_str());
+            let ty = field_type(&param["schema"], param["required"] == true)?;
+            writeln!(out, "    pub {}: {},", field_name(name), ty).unwrap();
+        }
+        out.push_str("}\n\n");
+
+        writeln!(out, "impl {} {{", params_name).unwrap();
+        let (required, optional): (Vec<&&Value>, Vec<&&Value>) = query_params
+            .iter()
+            .partition(|param| param["required"] == true && !schema_type(&param["schema"]).1);
+
+        out.push_str("    pub fn to_query(&self) -> Vec<(&'static str, String)> {\n");
+        // Required parameters only: return the literal directly
+        let binding = if optional.is_empty() {
+            ""
+        } else {
+            "let mut query = "
+        };
+        writeln!(out, "        {}vec![", binding).unwrap();
+        for param in &required {
+            let name = param["name"].as_str().unwrap_or_default();
+            let value = query_value(&format!("self.{}", field_name(name)), &param["schema"]);
+            writeln!(out, "            (\"{}\", {}),", name, value).unwrap();
+        }
+        out.push_str(if optional.is_empty() {
+            "        ]\n"
+        } else {
+            "        ];\n"
+        });
+        for param in &optional {
+            let name = param["name"].as_str().unwrap_or_default();
+            writeln!(
+                out,
+                "        if let Some(value) = &self.{} {{",
+                field_name(name)
+            )
+            .unwrap();
+            let value = query_value("value", &param["schema"]);
+            writeln!(out, "            query.push((\"{}\", {}));", name, value).unwrap();
+            out.push_str("        }\n");
+        }
+        if !optional.is_empty() {
+            out.push_str("        query\n");
+        }
+        out.push_str("    }\n}\n\n");
+    }
+
+    let response = &operation["responses"]["200"]["content"]["application/json"]["schema"];
+    let response_type = if response.is_null() {
+        "serde_json::Value".to_string()
+    } else {
+        rust_type(response)?
+    };
+
+    let mut args = String::new();
+    let mut format_args = String::new();
+    for param in &path_params {
+        let name = param["name"].as_str().unwrap_or_default();
+        let ty = match rust_type(&param["schema"])?.as_str() {
+            "String" => "&str".to_string(),
+            ty => ty.to_string(),
+        };
+        write!(args, ", {}: {}", field_name(name), ty).unwrap();
+        write!(format_args, ", {} = {}", name, field_name(name)).unwrap();
+    }
+    if !query_params.is_empty() {
+        write!(args, ", params: &{}", params_name).unwrap();
+    }
+
+    let endpoint = if path_params.is_empty() {
+        format!("\"{}\"", path)
+    } else {
+        format!("&format!(\"{}\"{})", path, format_args)
+    };
+    let query = if query_params.is_empty() {
+        "&[]"
+    } else {
+        "&params.to_query()"
+    };
+
+    doc(methods, "    ", operation["summary"].as_str());
+    writeln!(methods, "    ///\n    /// `GET {}`", path).unwrap();
+    writeln!(
+        methods,
+        "    pub async fn {}(&self{}) -> Result<{}, AppError> {{",
+        operation_id, args, response_type
+    )
+    .unwrap();
+    writeln!(methods, "        self.get({}, {}).await", endpoint, query).unwrap();
+    methods.push_str("    }\n\n");
+
+    Ok(())
+}
+
+/// Generates Rust source for the spec
+pub fn generate(spec: &Value) -> Result<String, String> {
+    let mut out = String::new();
+    let mut methods = String::new();
+
+    let empty = Map::new();
+    let schemas = spec["components"]["schemas"].as_object().unwrap_or(&empty);
+    for (name, schema) in schemas {
+        generate_model(&mut out, name, schema)?;
+    }
+
+    let paths = spec["paths"].as_object().unwrap_or(&empty);
+    for (path, item) in paths {
+        if let Some(operation) = item.get("get") {
+            generate_operation(&mut out, &mut methods, path, operation)?;
+        }
+    }
+
+    out.push_str("impl ApiClient {\n");
+    out.push_str(methods.trim_end());
+    out.push_str("\n}\n");
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn model(schema: Value) -> Result<String, String> {
+        let mut out = String::new();
+        generate_model(&mut out, "Entry", &schema)?;
+        Ok(out)
+    }
+
+    #[test]
+    fn maps_required_nullable_and_defaulted_fields() {
+        let code = model(json!({
+            "type": "object",
+            "required": ["account_id", "name"],
+            "properties": {
+                "account_id": { "type": "integer", "format": "int32", "minimum": 0 },
+                "name": { "type": ["string", "null"] },
+                "kills": { "type": "integer", "format": "int32", "minimum": 0, "default": 0 },
+                "items": { "type": "array", "items": { "type": "integer" }, "default": [] },
+                "type": { "type": "string" }
+            }
+        }))
+        .unwrap();
+
+        assert!(code.contains("    pub account_id: u32,\n"));
+        assert!(code.contains("    pub name: Option<String>,\n"));
+        assert!(code.contains("    #[serde(default)]\n    pub kills: u32,\n"));
+        assert!(code.contains("    #[serde(default)]\n    pub items: Vec<i64>,\n"));
+        assert!(code.contains("    pub r#type: Option<String>,\n"));
+        // Missing required fields must fail to decode
+        assert!(!code.contains("#[serde(default)]\npub struct"));
+    }
+
+    #[test]
+    fn unwraps_references_with_a_null_variant() {
+        let code = model(json!({
+            "type": "object",
+            "required": ["guid"],
+            "properties": {
+                "guid": {
+                    "oneOf": [{ "type": "null" }, { "$ref": "#/components/schemas/PatchGuid" }]
+                }
+            }
+        }))
+        .unwrap();
+
+        assert!(code.contains("    pub guid: Option<PatchGuid>,\n"));
+    }
+
+    #[test]
+    fn rejects_defaults_other_than_the_type_default() {
+        let error = model(json!({
+            "type": "object",
+            "properties": {
+                "limit": { "type": "integer", "default": 50 }
+            }
+        }))
+        .unwrap_err();
+
+        assert!(
+            error.starts_with("Entry.limit: unsupported default"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn generates_params_and_client_methods() {
+        let code = generate(&json!({
+            "paths": {
+                "/v1/leaderboard/{region}/{hero_id}": {
+                    "get": {
+                        "operationId": "get_hero_leaderboard",
+                        "parameters": [
+                            { "name": "region", "in": "path", "required": true, "schema": { "type": "string" } },
+                            { "name": "hero_id", "in": "path", "required": true, "schema": { "type": "integer", "format": "int32", "minimum": 0 } },
+                            { "name": "account_ids", "in": "query", "required": true, "schema": { "type": "array", "items": { "type": "integer" } } },
+                            { "name": "limit", "in": "query", "required": false, "schema": { "type": "integer", "format": "int32", "minimum": 0 } }
+                        ],
+                        "responses": {
+                            "200": { "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Leaderboard" } } } }
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        assert!(code.contains("pub struct GetHeroLeaderboardParams {"));
+        assert!(code.contains("    pub account_ids: Vec<i64>,\n"));
+        assert!(code.contains("    pub limit: Option<u32>,\n"));
+        assert!(code.contains(
+            "(\"account_ids\", self.account_ids.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(\",\")),"
+        ));
+        assert!(code.contains("if let Some(value) = &self.limit {"));
+        assert!(code.contains(
+            "pub async fn get_hero_leaderboard(&self, region: &str, hero_id: u32, params: &GetHeroLeaderboardParams) -> Result<Leaderboard, AppError> {"
+        ));
+        assert!(code.contains(
+            "self.get(&format!(\"/v1/leaderboard/{region}/{hero_id}\", region = region, hero_id = hero_id), &params.to_query()).await"
+        ));
+    }
+
+    #[test]
+    fn requires_operation_ids() {
+        let error = generate(&json!({
+            "paths": { "/v1/patches": { "get": { "responses": {} } } }
+        }))
+        .unwrap_err();
+
+        assert_eq!(error, "GET /v1/patches: missing operationId");
+    }
+}
@@ -0,0 +1,364 @@
+//! Counter-pick advisor (GET /v1/analytics/hero-counter-stats)
+//!
+//! Ranks heroes by their combined win rate against the enemy lineup and
+//! suggests items for the best pick, ranked by their win rate in matches
+//! against the same enemy heroes.
+
+use std::collections::HashMap;
+
+use futures_util::future::join_all;
+use serde::Serialize;
+use tauri::State;
+
+use super::items::{
+    fetch_matchup_item_stats, resolve_items, ItemStats, ItemSummary, MIN_POPULARITY,
+};
+use super::{wilson_lower_bound, StatsFilter, StatsQuery};
+use crate::api::generated::GetHeroCounterStatsParams;
+use crate::api::ApiClient;
+use crate::assets::{Assets, ResolvedAsset};
+use crate::error::AppError;
+use crate::settings::SettingsStore;
+
+/// Matches per matchup required for medium / high confidence
+const MEDIUM_CONFIDENCE_MATCHES: u64 = 200;
+const HIGH_CONFIDENCE_MATCHES: u64 = 1000;
+
+const MAX_PICKS: usize = 10;
+const MAX_ITEMS: usize = 8;
+
+/// Hero vs enemy hero entry from the API (model from the OpenAPI spec)
+pub use crate::api::generated::HeroCounterStats;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Confidence {
+    Low,
+    Medium,
+    High,
+}
+
+impl Confidence {
+    /// Confidence is limited by the least played matchup
+    fn from_sample(min_matchup_matches: u64) -> Self {
+        if min_matchup_matches >= HIGH_CONFIDENCE_MATCHES {
+            Self::High
+        } else if min_matchup_matches >= MEDIUM_CONFIDENCE_MATCHES {
+            Self::Medium
+        } else {
+            Self::Low
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Matchup {
+    pub enemy_hero_id: u32,
+    /// Name and icon in the UI language
+    pub enemy_hero: Option<ResolvedAsset>,
+    pub matches: u64,
+    pub win_rate: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CounterPick {
+    pub hero_id: u32,
+    /// Name and icon in the UI language
+    pub hero: Option<ResolvedAsset>,
+    /// Combined win rate against the enemy heroes
+    pub win_rate: f64,
+    /// Difference from the hero's overall win rate
+    pub advantage: f64,
+    pub matches: u64,
+    /// Win rate lower bound used for ranking
+    pub score: f64,
+    pub confidence: Confidence,
+    pub matchups: Vec<Matchup>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CounterAdvice {
+    pub enemy_hero_ids: Vec<u32>,
+    /// Best first
+    pub picks: Vec<CounterPick>,
+    /// Hero the items are suggested for (chosen hero or the best pick)
+    pub item_hero_id: Option<u32>,
+    /// Best first; popularity is relative to the hero's matches against the
+    /// enemy heroes, timings are not split
+    pub items: Vec<ItemSummary>,
+}
+
+/// Fetches hero matchup stats for the resolved filter
+pub async fn fetch_counter_stats(
+    api: &ApiClient,
+    query: &StatsQuery,
+) -> Result<Vec<HeroCounterStats>, AppError> {
+    let params = GetHeroCounterStatsParams {
+        min_unix_timestamp: query.min_unix_timestamp,
+        max_unix_timestamp: query.max_unix_timestamp,
+        min_average_badge: query.min_average_badge,
+        max_average_badge: query.max_average_badge,
+    };
+    api.get_hero_counter_stats(&params).await
+}
+
+/// Ranks heroes against the enemy lineup
+pub fn rank_picks(
+    stats: &[HeroCounterStats],
+    enemy_hero_ids: &[u32],
+    excluded_hero_ids: &[u32],
+) -> Vec<CounterPick> {
+    // Overall win rate of each hero across all matchups
+    let mut baseline: HashMap<u32, (u64, u64)> = HashMap::new();
+    for entry in stats {
+        let (wins, matches) = baseline.entry(entry.hero_id).or_default();
+        *wins += entry.wins;
+        *matches += entry.matches_played;
+    }
+
+    let mut picks: Vec<CounterPick> = baseline
+        .iter()
+        .filter(|(hero_id, _)| {
+            !enemy_hero_ids.contains(hero_id) && !excluded_hero_ids.contains(hero_id)
+        })
+        .filter_map(|(&hero_id, &(base_wins, base_matches))| {
+            let matchups: Vec<Matchup> = enemy_hero_ids
+                .iter()
+                .filter_map(|&enemy_hero_id| {
+                    stats
+                        .iter()
+                        .find(|entry| {
+                            entry.hero_id == hero_id && entry.enemy_hero_id == enemy_hero_id
+                        })
+                        .filter(|entry| entry.matches_played > 0)
+                        .map(|entry| Matchup {
+                            enemy_hero_id,
+                            enemy_hero: None,
+                            matches: entry.matches_played,
+                            win_rate: entry.wins as f64 / entry.matches_played as f64,
+                        })
+                })
+                .collect();
+
+            // Heroes without data against every enemy can't be compared fairly
+            if matchups.len() != enemy_hero_ids.len() || base_matches == 0 {
+                return None;
+            }
+
+            let wins: u64 = stats
+                .iter()
+                .filter(|entry| {
+                    entry.hero_id == hero_id && enemy_hero_ids.contains(&entry.enemy_hero_id)
+                })
+                .map(|entry| entry.wins)
+                .sum();
+            let matches: u64 = matchups.iter().map(|matchup| matchup.matches).sum();
+            let win_rate = wins as f64 / matches as f64;
+            let min_matchup = matchups.iter().map(|m| m.matches).min().unwrap_or(0);
+
+            Some(CounterPick {
+                hero_id,
+                hero: None,
+                win_rate,
+                advantage: win_rate - base_wins as f64 / base_matches as f64,
+                matches,
+                score: wilson_lower_bound(wins, matches),
+                confidence: Confidence::from_sample(min_matchup),
+                matchups,
+            })
+        })
+        .collect();
+
+    picks.sort_by(|a, b| b.score.total_cmp(&a.score));
+    picks
+}
+
+/// Drops repeated IDs, keeping the first occurrence
+fn unique(ids: Vec<u32>) -> Vec<u32> {
+    let mut unique = Vec::with_capacity(ids.len());
+    for id in ids {
+        if !unique.contains(&id) {
+            unique.push(id);
+        }
+    }
+    unique
+}
+
+/// Ranks items of a hero by their stats summed over matchups with the enemy heroes
+pub fn rank_matchup_items(per_enemy: &[Vec<ItemStats>], hero_matches: u64) -> Vec<ItemSummary> {
+    let mut totals: HashMap<u32, (u64, u64)> = HashMap::new();
+    for item in per_enemy.iter().flatten() {
+        let (wins, matches) = totals.entry(item.item_id).or_default();
+        *wins += item.wins;
+        *matches += item.matches;
+    }
+
+    let mut items: Vec<ItemSummary> = totals
+        .into_iter()
+        .filter(|(_, (_, matches))| *matches > 0)
+        .map(|(item_id, (wins, matches))| ItemSummary {
+            item_id,
+            item: None,
+            matches,
+            win_rate: wins as f64 / matches as f64,
+            popularity: if hero_matches > 0 {
+                matches as f64 / hero_matches as f64
+            } else {
+                0.0
+            },
+            score: wilson_lower_bound(wins, matches),
+            timings: Vec::new(),
+        })
+        .filter(|item| item.popularity >= MIN_POPULARITY)
+        .collect();
+
+    items.sort_by(|a, b| b.score.total_cmp(&a.score));
+    items
+}
+
+/// Returns best heroes to pick against the enemy lineup and items to prioritize
+#[tauri::command]
+pub async fn get_counter_picks(
+    api: State<'_, ApiClient>,
+    assets: State<'_, Assets>,
+    settings: State<'_, SettingsStore>,
+    enemy_hero_ids: Vec<u32>,
+    excluded_hero_ids: Option<Vec<u32>>,
+    item_hero_id: Option<u32>,
+    filter: Option<StatsFilter>,
+) -> Result<CounterAdvice, AppError> {
+    // A hero listed twice would count its matchup twice
+    let enemy_hero_ids = unique(enemy_hero_ids);
+    if enemy_hero_ids.is_empty() {
+        return Err(AppError::InvalidInput {
+            field: "enemy_hero_ids".to_string(),
+            reason: "no enemy heroes selected".to_string(),
+        });
+    }
+
+    let query = filter.unwrap_or_default().to_query(&api).await?;
+    let stats = fetch_counter_stats(&api, &query).await?;
+
+    let mut picks = rank_picks(
+        &stats,
+        &enemy_hero_ids,
+        &excluded_hero_ids.unwrap_or_default(),
+    );
+    picks.truncate(MAX_PICKS);
+
+    let item_hero_id = item_hero_id.or_else(|| picks.first().map(|pick| pick.hero_id));
+    let mut items = Vec::new();
+    if let Some(hero_id) = item_hero_id {
+        let per_enemy =
+            join_all(enemy_hero_ids.iter().map(|&enemy_hero_id| {
+                fetch_matchup_item_stats(&api, &query, hero_id, enemy_hero_id)
+            }))
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+        let hero_matches: u64 = stats
+            .iter()
+            .filter(|entry| {
+                entry.hero_id == hero_id && enemy_hero_ids.contains(&entry.enemy_hero_id)
+            })
+            .map(|entry| entry.matches_played)
+            .sum();
+        items = rank_matchup_items(&per_enemy, hero_matches);
+        items.truncate(MAX_ITEMS);
+    }
+
+    let language = settings.get().language;
+    for pick in &mut picks {
+        pick.hero = assets.hero(pick.hero_id, &language);
+        for matchup in &mut pick.matchups {
+            matchup.enemy_hero = assets.hero(matchup.enemy_hero_id, &language);
+        }
+    }
+    resolve_items(&mut items, &assets, &language);
+
+    Ok(CounterAdvice {
+        enemy_hero_ids,
+        picks,
+        item_hero_id,
+        items,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(item_id: u32, wins: u64, matches: u64) -> ItemStats {
+        ItemStats {
+            item_id,
+            bucket: None,
+            wins,
+            losses: matches - wins,
+            matches,
+            players: matches,
+        }
+    }
+
+    fn matchup(
+        hero_id: u32,
+        enemy_hero_id: u32,
+        wins: u64,
+        matches_played: u64,
+    ) -> HeroCounterStats {
+        HeroCounterStats {
+            hero_id,
+            enemy_hero_id,
+            wins,
+            matches_played,
+        }
+    }
+
+    #[test]
+    fn removes_repeated_enemy_heroes() {
+        assert_eq!(unique(vec![3, 1, 3, 2, 1]), [3, 1, 2]);
+    }
+
+    #[test]
+    fn ranks_picks_against_repeated_enemy_heroes_once() {
+        let stats = [
+            matchup(1, 5, 700, 1000),
+            matchup(1, 6, 300, 1000),
+            matchup(2, 5, 450, 1000),
+            matchup(2, 6, 580, 1000),
+            // Hero 3 never met hero 6
+            matchup(3, 5, 900, 1000),
+            matchup(5, 1, 300, 1000),
+            matchup(6, 1, 700, 1000),
+        ];
+
+        let picks = rank_picks(&stats, &unique(vec![5, 6, 5]), &[]);
+        let ids: Vec<u32> = picks.iter().map(|pick| pick.hero_id).collect();
+
+        assert_eq!(ids, [2, 1]);
+        assert_eq!((picks[0].matches, picks[0].win_rate), (2000, 0.515));
+        assert_eq!((picks[1].matches, picks[1].win_rate), (2000, 0.5));
+        assert_eq!(picks[0].matchups.len(), 2);
+        assert_eq!(picks[0].confidence, Confidence::High);
+
+        assert!(rank_picks(&stats, &[5, 6], &[2])
+            .iter()
+            .all(|pick| pick.hero_id == 1));
+    }
+
+    #[test]
+    fn ranks_items_over_all_enemy_matchups() {
+        let per_enemy = vec![
+            vec![item(1, 60, 100), item(2, 40, 100), item(3, 1, 1)],
+            vec![item(1, 50, 100), item(2, 60, 100)],
+        ];
+
+        let items = rank_matchup_items(&per_enemy, 400);
+        let ids: Vec<u32> = items.iter().map(|item| item.item_id).collect();
+
+        // Item 3 is bought in under 1% of the matches
+        assert_eq!(ids, [1, 2]);
+        assert_eq!(items[0].matches, 200);
+        assert_eq!(items[0].win_rate, 0.55);
+        assert_eq!(items[0].popularity, 0.5);
+    }
+}
@@ -0,0 +1,181 @@
+//! Hero statistics and tier lists (GET /v1/analytics/hero-stats)
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use super::{wilson_lower_bound, StatsFilter, StatsQuery};
+use crate::api::generated::GetHeroStatsParams;
+use crate::api::ApiClient;
+use crate::assets::{Assets, ResolvedAsset};
+use crate::error::AppError;
+use crate::settings::SettingsStore;
+
+/// Heroes per match (6 per team)
+const HEROES_PER_MATCH: f64 = 12.0;
+
+/// Hero stats entry from the API (model from the OpenAPI spec)
+pub use crate::api::generated::HeroStats;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Tier {
+    S,
+    A,
+    B,
+    C,
+    D,
+}
+
+impl Tier {
+    /// Tier by position in ranking (0.0 = best, 1.0 = worst)
+    fn from_percentile(percentile: f64) -> Self {
+        match percentile {
+            p if p < 0.10 => Self::S,
+            p if p < 0.30 => Self::A,
+            p if p < 0.70 => Self::B,
+            p if p < 0.90 => Self::C,
+            _ => Self::D,
+        }
+    }
+}
+
+/// Hero entry of the tier list
+#[derive(Debug, Clone, Serialize)]
+pub struct HeroSummary {
+    pub hero_id: u32,
+    /// Name and icon in the UI language
+    pub hero: Option<ResolvedAsset>,
+    pub matches: u64,
+    pub win_rate: f64,
+    pub pick_rate: f64,
+    pub kda: f64,
+    /// Win rate lower bound used for ranking (accounts for sample size)
+    pub score: f64,
+    pub tier: Tier,
+}
+
+/// Heroes ranked within a rank bracket and time window
+#[derive(Debug, Clone, Serialize)]
+pub struct TierList {
+    pub filter: StatsFilter,
+    pub total_matches: u64,
+    /// Best first
+    pub heroes: Vec<HeroSummary>,
+}
+
+/// Fetches hero stats for the resolved filter
+pub async fn fetch_hero_stats(
+    api: &ApiClient,
+    query: &StatsQuery,
+) -> Result<Vec<HeroStats>, AppError> {
+    let params = GetHeroStatsParams {
+        min_unix_timestamp: query.min_unix_timestamp,
+        max_unix_timestamp: query.max_unix_timestamp,
+        min_average_badge: query.min_average_badge,
+        max_average_badge: query.max_average_badge,
+    };
+    api.get_hero_stats(&params).await
+}
+
+/// Computes tier list from hero stats
+pub fn build_tier_list(filter: StatsFilter, stats: &[HeroStats]) -> TierList {
+    let hero_matches: u64 = stats.iter().map(|hero| hero.matches).sum();
+    let total_matches = (hero_matches as f64 / HEROES_PER_MATCH).round() as u64;
+
+    let mut heroes: Vec<HeroSummary> = stats
+        .iter()
+        .filter(|hero| hero.matches > 0)
+        .map(|hero| HeroSummary {
+            hero_id: hero.hero_id,
+            hero: None,
+            matches: hero.matches,
+            win_rate: hero.wins as f64 / hero.matches as f64,
+            pick_rate: if total_matches > 0 {
+                hero.matches as f64 / total_matches as f64
+            } else {
+                0.0
+            },
+            kda: (hero.total_kills + hero.total_assists) as f64 / hero.total_deaths.max(1) as f64,
+            score: wilson_lower_bound(hero.wins, hero.matches),
+            tier: Tier::D,
+        })
+        .collect();
+
+    heroes.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+    let count = heroes.len() as f64;
+    for (index, hero) in heroes.iter_mut().enumerate() {
+        hero.tier = Tier::from_percentile(index as f64 / count);
+    }
+
+    TierList {
+        filter,
+        total_matches,
+        heroes,
+    }
+}
+
+/// Returns hero tier list for the rank bracket and time window
+#[tauri::command]
+pub async fn get_hero_tier_list(
+    api: State<'_, ApiClient>,
+    assets: State<'_, Assets>,
+    settings: State<'_, SettingsStore>,
+    filter: Option<StatsFilter>,
+) -> Result<TierList, AppError> {
+    let filter = filter.unwrap_or_default();
+    let query = filter.to_query(&api).await?;
+    let stats = fetch_hero_stats(&api, &query).await?;
+
+    let mut list = build_tier_list(filter, &stats);
+    let language = settings.get().language;
+    for hero in &mut list.heroes {
+        hero.hero = assets.hero(hero.hero_id, &language);
+    }
+    Ok(list)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hero(hero_id: u32, wins: u64, matches: u64) -> HeroStats {
+        HeroStats {
+            hero_id,
+            wins,
+            losses: matches - wins,
+            matches,
+            players: matches,
+            total_kills: 0,
+            total_deaths: 0,
+            total_assists: 0,
+        }
+    }
+
+    #[test]
+    fn ranks_by_sample_adjusted_win_rate() {
+        let stats: Vec<HeroStats> = (1..=10)
+            .map(|hero_id| hero(hero_id, 400 + u64::from(hero_id) * 10, 1000))
+            .chain([hero(11, 6, 10), hero(12, 0, 0)])
+            .collect();
+
+        let list = build_tier_list(StatsFilter::default(), &stats);
+        let ids: Vec<u32> = list.heroes.iter().map(|hero| hero.hero_id).collect();
+
+        // 60% over 10 matches ranks below 41% over 1000; heroes without matches are skipped
+        assert_eq!(ids[0], 10);
+        assert!(!ids.contains(&12));
+        assert_eq!(list.heroes.last().unwrap().hero_id, 11);
+        assert_eq!(list.heroes[0].tier, Tier::S);
+        assert_eq!(list.heroes.last().unwrap().tier, Tier::D);
+        assert_eq!(list.total_matches, 10_010 / 12);
+    }
+
+    #[test]
+    fn splits_tiers_by_percentile() {
+        let tiers: Vec<Tier> = [0.0, 0.1, 0.3, 0.7, 0.9]
+            .into_iter()
+            .map(Tier::from_percentile)
+            .collect();
+        assert_eq!(tiers, [Tier::S, Tier::A, Tier::B, Tier::C, Tier::D]);
+    }
+}
@@ -0,0 +1,247 @@
+//! Item statistics and build win rates (GET /v1/analytics/item-stats)
+
+use serde::Serialize;
+use tauri::State;
+
+use super::heroes::fetch_hero_stats;
+use super::{wilson_lower_bound, StatsFilter, StatsQuery};
+use crate::api::generated::GetItemStatsParams;
+use crate::api::ApiClient;
+use crate::assets::{Assets, ResolvedAsset};
+use crate::error::AppError;
+use crate::settings::SettingsStore;
+
+/// Purchase timing buckets (minutes, upper bound exclusive)
+const TIMING_BUCKETS: &[(u32, Option<u32>)] =
+    &[(0, Some(10)), (10, Some(20)), (20, Some(30)), (30, None)];
+
+/// Items bought in fewer of the hero's matches are left out of top items
+pub(super) const MIN_POPULARITY: f64 = 0.01;
+
+/// Item stats entry from the API (model from the OpenAPI spec)
+pub use crate::api::generated::ItemStats;
+
+/// Win rate of an item by purchase time
+#[derive(Debug, Clone, Serialize)]
+pub struct TimingBucket {
+    pub from_min: u32,
+    /// `None` for the last bucket
+    pub to_min: Option<u32>,
+    pub matches: u64,
+    pub win_rate: f64,
+}
+
+/// Item analytics as sent to the UI
+#[derive(Debug, Clone, Serialize)]
+pub struct ItemSummary {
+    pub item_id: u32,
+    /// Name and icon in the UI language
+    pub item: Option<ResolvedAsset>,
+    pub matches: u64,
+    /// Win rate when purchased
+    pub win_rate: f64,
+    /// Share of the hero's matches (all matches without hero filter) where the item was bought
+    pub popularity: f64,
+    /// Win rate lower bound used for ranking (accounts for sample size)
+    pub score: f64,
+    pub timings: Vec<TimingBucket>,
+}
+
+/// Item stats params of the resolved filter
+fn item_params(query: &StatsQuery, hero_id: Option<u32>) -> GetItemStatsParams {
+    GetItemStatsParams {
+        min_unix_timestamp: query.min_unix_timestamp,
+        max_unix_timestamp: query.max_unix_timestamp,
+        min_average_badge: query.min_average_badge,
+        max_average_badge: query.max_average_badge,
+        hero_id,
+        ..GetItemStatsParams::default()
+    }
+}
+
+async fn fetch_item_stats(
+    api: &ApiClient,
+    query: &StatsQuery,
+    hero_id: Option<u32>,
+    bucket: Option<&str>,
+) -> Result<Vec<ItemStats>, AppError> {
+    let params = GetItemStatsParams {
+        bucket: bucket.map(str::to_string),
+        ..item_params(query, hero_id)
+    };
+    api.get_item_stats(&params).await
+}
+
+/// Fetches item stats of the hero in matches against the enemy hero
+pub async fn fetch_matchup_item_stats(
+    api: &ApiClient,
+    query: &StatsQuery,
+    hero_id: u32,
+    enemy_hero_id: u32,
+) -> Result<Vec<ItemStats>, AppError> {
+    let params = GetItemStatsParams {
+        enemy_hero_ids: Some(vec![enemy_hero_id]),
+        ..item_params(query, Some(hero_id))
+    };
+    api.get_item_stats(&params).await
+}
+
+/// Groups per-minute stats of an item into timing buckets
+fn timing_buckets(item_id: u32, by_minute: &[ItemStats]) -> Vec<TimingBucket> {
+    TIMING_BUCKETS
+        .iter()
+        .map(|&(from_min, to_min)| {
+            let (wins, matches) = by_minute
+                .iter()
+                .filter(|entry| entry.item_id == item_id)
+                .filter(|entry| {
+                    let minute = entry.bucket.unwrap_or(0);
+                    minute >= from_min && to_min.is_none_or(|to| minute < to)
+                })
+                .fold((0, 0), |(wins, matches), entry| {
+                    (wins + entry.wins, matches + entry.matches)
+                });
+
+            TimingBucket {
+                from_min,
+                to_min,
+                matches,
+                win_rate: if matches > 0 {
+                    wins as f64 / matches as f64
+                } else {
+                    0.0
+                },
+            }
+        })
+        .collect()
+}
+
+/// Fetches item analytics for the resolved filter (optionally for a single hero), best first
+pub async fn item_analytics(
+    api: &ApiClient,
+    query: &StatsQuery,
+    hero_id: Option<u32>,
+) -> Result<Vec<ItemSummary>, AppError> {
+    let (totals, by_minute, heroes) = tokio::join!(
+        fetch_item_stats(api, query, hero_id, None),
+        fetch_item_stats(api, query, hero_id, Some("game_time_min")),
+        fetch_hero_stats(api, query),
+    );
+    let (totals, by_minute, heroes) = (totals?, by_minute?, heroes?);
+
+    let hero_matches: u64 = heroes
+        .iter()
+        .filter(|hero| hero_id.is_none_or(|id| hero.hero_id == id))
+        .map(|hero| hero.matches)
+        .sum();
+
+    let mut items: Vec<ItemSummary> = totals
+        .iter()
+        .filter(|item| item.matches > 0)
+        .map(|item| ItemSummary {
+            item_id: item.item_id,
+            item: None,
+            matches: item.matches,
+            win_rate: item.wins as f64 / item.matches as f64,
+            popularity: if hero_matches > 0 {
+                item.matches as f64 / hero_matches as f64
+            } else {
+                0.0
+            },
+            score: wilson_lower_bound(item.wins, item.matches),
+            timings: timing_buckets(item.item_id, &by_minute),
+        })
+        .collect();
+
+    items.sort_by(|a, b| b.score.total_cmp(&a.score));
+    Ok(items)
+}
+
+/// Fills in item names and icons in the language
+pub fn resolve_items(items: &mut [ItemSummary], assets: &Assets, language: &str) {
+    for item in items {
+        item.item = assets.item(item.item_id, language);
+    }
+}
+
+/// Returns item analytics filtered by hero, rank bracket and time window
+#[tauri::command]
+pub async fn get_item_stats(
+    api: State<'_, ApiClient>,
+    assets: State<'_, Assets>,
+    settings: State<'_, SettingsStore>,
+    filter: Option<StatsFilter>,
+    hero_id: Option<u32>,
+) -> Result<Vec<ItemSummary>, AppError> {
+    let query = filter.unwrap_or_default().to_query(&api).await?;
+    let mut items = item_analytics(&api, &query, hero_id).await?;
+    resolve_items(&mut items, &assets, &settings.get().language);
+    Ok(items)
+}
+
+/// Returns top-performing items of the hero (rarely bought items are skipped)
+#[tauri::command]
+pub async fn get_top_items(
+    api: State<'_, ApiClient>,
+    assets: State<'_, Assets>,
+    settings: State<'_, SettingsStore>,
+    hero_id: u32,
+    filter: Option<StatsFilter>,
+    limit: Option<usize>,
+) -> Result<Vec<ItemSummary>, AppError> {
+    let query = filter.unwrap_or_default().to_query(&api).await?;
+    let mut items: Vec<ItemSummary> = item_analytics(&api, &query, Some(hero_id))
+        .await?
+        .into_iter()
+        .filter(|item| item.popularity >= MIN_POPULARITY)
+        .take(limit.unwrap_or(12))
+        .collect();
+    resolve_items(&mut items, &assets, &settings.get().language);
+    Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn minute(item_id: u32, bucket: u32, wins: u64, matches: u64) -> ItemStats {
+        ItemStats {
+            item_id,
+            bucket: Some(bucket),
+            wins,
+            losses: matches - wins,
+            matches,
+            players: matches,
+        }
+    }
+
+    #[test]
+    fn groups_purchase_minutes_into_buckets() {
+        let by_minute = [
+            minute(1, 5, 3, 4),
+            minute(1, 10, 1, 2),
+            minute(1, 19, 1, 2),
+            minute(1, 45, 0, 1),
+            minute(2, 5, 10, 10),
+        ];
+
+        let buckets = timing_buckets(1, &by_minute);
+        let summary: Vec<(u32, Option<u32>, u64)> = buckets
+            .iter()
+            .map(|bucket| (bucket.from_min, bucket.to_min, bucket.matches))
+            .collect();
+
+        assert_eq!(
+            summary,
+            [
+                (0, Some(10), 4),
+                (10, Some(20), 4),
+                (20, Some(30), 0),
+                (30, None, 1)
+            ]
+        );
+        assert_eq!(buckets[0].win_rate, 0.75);
+        assert_eq!(buckets[1].win_rate, 0.5);
+        assert_eq!(buckets[2].win_rate, 0.0);
+    }
+}
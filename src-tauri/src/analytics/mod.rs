@@ -0,0 +1,191 @@
+//! Analytics endpoints (GET /v1/analytics/...)
+//!
+//! Shared filters: rank bracket (badge range) and time window (last N days or
+//! a patch from the patch timeline).
+
+pub mod counters;
+pub mod heroes;
+pub mod items;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::api::generated::GetBadgeDistributionParams;
+use crate::api::ApiClient;
+use crate::error::AppError;
+use crate::patches::{self, PatchSelector, PatchTimeline};
+
+/// Default time window (same as badge distribution script)
+const DEFAULT_WINDOW_DAYS: u32 = 30;
+
+/// Rank badge: first digits = tier, last digit = subtier (e.g. 116 = tier 11, subtier 6)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Badge {
+    pub tier: u32,
+    pub subtier: u32,
+}
+
+impl Badge {
+    pub fn from_level(badge_level: u32) -> Self {
+        Self {
+            tier: badge_level / 10,
+            subtier: badge_level % 10,
+        }
+    }
+
+    pub fn level(self) -> u32 {
+        self.tier * 10 + self.subtier
+    }
+}
+
+/// Decoded badge distribution entry as sent to the UI
+#[derive(Debug, Clone, Serialize)]
+pub struct BadgeCount {
+    pub badge: Badge,
+    pub total_matches: u64,
+}
+
+/// Time window of analytics queries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StatsWindow {
+    LastDays { days: u32 },
+    Patch { patch: PatchSelector },
+}
+
+impl Default for StatsWindow {
+    fn default() -> Self {
+        Self::LastDays {
+            days: DEFAULT_WINDOW_DAYS,
+        }
+    }
+}
+
+/// Resolved filter as sent to the analytics endpoints
+///
+/// Commands resolve their filter once and pass the query down, so a patch
+/// window looks up the patch timeline only once per command.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatsQuery {
+    pub min_unix_timestamp: Option<i64>,
+    pub max_unix_timestamp: Option<i64>,
+    pub min_average_badge: Option<u32>,
+    pub max_average_badge: Option<u32>,
+}
+
+/// Common analytics filter (rank bracket + time window)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StatsFilter {
+    /// Lowest average badge of the match (inclusive)
+    #[serde(default)]
+    pub min_badge: Option<Badge>,
+    /// Highest average badge of the match (inclusive)
+    #[serde(default)]
+    pub max_badge: Option<Badge>,
+    #[serde(default)]
+    pub window: StatsWindow,
+}
+
+impl StatsFilter {
+    /// Resolves filter into API query parameters
+    pub async fn to_query(&self, api: &ApiClient) -> Result<StatsQuery, AppError> {
+        let mut query = StatsQuery {
+            min_average_badge: self.min_badge.map(Badge::level),
+            max_average_badge: self.max_badge.map(Badge::level),
+            ..StatsQuery::default()
+        };
+
+        match self.window {
+            StatsWindow::LastDays { days } => {
+                let since = Utc::now().timestamp() - i64::from(days) * 24 * 60 * 60;
+                query.min_unix_timestamp = Some(since);
+            }
+            StatsWindow::Patch { patch } => {
+                let notes = patches::fetch_patch_notes(api).await?;
+                let timeline = PatchTimeline::from_patches(&notes);
+                let period = timeline.select(patch).ok_or_else(|| {
+                    AppError::not_found("patch", format!("{:?}", patch).to_lowercase())
+                })?;
+
+                let (start, end) = period.unix_range();
+                query.min_unix_timestamp = Some(start);
+                query.max_unix_timestamp = end;
+            }
+        }
+
+        Ok(query)
+    }
+}
+
+/// Lower bound of Wilson score interval (95%): win rate discounted by sample size
+pub fn wilson_lower_bound(wins: u64, matches: u64) -> f64 {
+    if matches == 0 {
+        return 0.0;
+    }
+
+    const Z: f64 = 1.96;
+    let n = matches as f64;
+    let p = wins as f64 / n;
+    let denominator = 1.0 + Z * Z / n;
+    let center = p + Z * Z / (2.0 * n);
+    let margin = Z * ((p * (1.0 - p) + Z * Z / (4.0 * n)) / n).sqrt();
+
+    (center - margin) / denominator
+}
+
+/// Returns player rank distribution for the time window
+#[tauri::command]
+pub async fn get_badge_distribution(
+    api: State<'_, ApiClient>,
+    window: Option<StatsWindow>,
+) -> Result<Vec<BadgeCount>, AppError> {
+    let filter = StatsFilter {
+        window: window.unwrap_or_default(),
+        ..StatsFilter::default()
+    };
+    let query = filter.to_query(&api).await?;
+    let params = GetBadgeDistributionParams {
+        min_unix_timestamp: query.min_unix_timestamp,
+        max_unix_timestamp: query.max_unix_timestamp,
+        min_average_badge: query.min_average_badge,
+        max_average_badge: query.max_average_badge,
+    };
+    let distribution = api.get_badge_distribution(&params).await?;
+
+    Ok(distribution
+        .into_iter()
+        .map(|entry| BadgeCount {
+            badge: Badge::from_level(entry.badge_level),
+            total_matches: entry.total_matches,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wilson_lower_bound_discounts_small_samples() {
+        assert_eq!(wilson_lower_bound(0, 0), 0.0);
+        // Same win rate, more matches -> higher bound
+        assert!(wilson_lower_bound(6, 10) < wilson_lower_bound(600, 1000));
+        assert!(wilson_lower_bound(600, 1000) < 0.6);
+        // Known value for 50/100
+        assert!((wilson_lower_bound(50, 100) - 0.4038).abs() < 1e-4);
+    }
+
+    #[test]
+    fn badge_level_round_trips() {
+        let badge = Badge::from_level(116);
+        assert_eq!(
+            badge,
+            Badge {
+                tier: 11,
+                subtier: 6
+            }
+        );
+        assert_eq!(badge.level(), 116);
+    }
+}
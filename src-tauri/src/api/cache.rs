@@ -0,0 +1,346 @@
+//! Conditional requests and in-flight request coalescing
+//!
+//! Responses carrying `ETag` or `Last-Modified` are kept in memory (within a
+//! budget of response bytes) and revalidated with `If-None-Match` /
+//! `If-Modified-Since`; a 304 reuses the cached body. Identical concurrent
+//! requests (same URL) share one network call. Hits and misses are counted for
+//! the diagnostics command.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use reqwest::header::{HeaderMap, HeaderValue, IF_MODIFIED_SINCE, IF_NONE_MATCH};
+use serde::Serialize;
+use serde_json::Value;
+use tauri::State;
+use tokio::sync::OnceCell;
+
+use super::drift::DriftIssue;
+use super::notices::Notice;
+use super::ApiClient;
+use crate::error::AppError;
+
+/// Size of cached response bodies as received (least recently used are evicted)
+const MAX_CACHED_BYTES: usize = 32 * 1024 * 1024;
+
+/// Result shared by coalesced requests
+pub type SharedResponse = Result<Arc<Value>, AppError>;
+
+struct CachedResponse {
+    etag: Option<HeaderValue>,
+    last_modified: Option<HeaderValue>,
+    body: Arc<Value>,
+    /// Response body size in bytes
+    size: usize,
+    last_used: Instant,
+}
+
+#[derive(Default)]
+struct Entries {
+    responses: HashMap<String, CachedResponse>,
+    /// Sum of `size` over all responses
+    bytes: usize,
+}
+
+impl Entries {
+    fn remove(&mut self, url: &str) {
+        if let Some(removed) = self.responses.remove(url) {
+            self.bytes -= removed.size;
+        }
+    }
+
+    fn evict_oldest(&mut self) {
+        let oldest = self
+            .responses
+            .iter()
+            .min_by_key(|(_, cached)| cached.last_used)
+            .map(|(key, _)| key.clone());
+        if let Some(oldest) = oldest {
+            self.remove(&oldest);
+        }
+    }
+}
+
+/// Revalidatable responses keyed by request URL
+pub struct ResponseCache {
+    entries: Mutex<Entries>,
+    max_bytes: usize,
+}
+
+impl Default for ResponseCache {
+    fn default() -> Self {
+        Self::with_limit(MAX_CACHED_BYTES)
+    }
+}
+
+impl ResponseCache {
+    fn with_limit(max_bytes: usize) -> Self {
+        Self {
+            entries: Mutex::default(),
+            max_bytes,
+        }
+    }
+
+    /// Adds validators of the cached response to the request headers
+    pub fn conditional_headers(&self, url: &str, headers: &mut HeaderMap) -> bool {
+        let entries = self.entries.lock().unwrap();
+        let Some(cached) = entries.responses.get(url) else {
+            return false;
+        };
+
+        if let Some(etag) = &cached.etag {
+            headers.insert(IF_NONE_MATCH, etag.clone());
+        }
+        if let Some(last_modified) = &cached.last_modified {
+            headers.insert(IF_MODIFIED_SINCE, last_modified.clone());
+        }
+        true
+    }
+
+    /// Cached body after a 304 response
+    pub fn revalidated(&self, url: &str) -> Option<Arc<Value>> {
+        let mut entries = self.entries.lock().unwrap();
+        let cached = entries.responses.get_mut(url)?;
+        cached.last_used = Instant::now();
+        Some(Arc::clone(&cached.body))
+    }
+
+    /// Cached body without marking it as used
+    pub fn peek(&self, url: &str) -> Option<Arc<Value>> {
+        let entries = self.entries.lock().unwrap();
+        entries
+            .responses
+            .get(url)
+            .map(|cached| Arc::clone(&cached.body))
+    }
+
+    /// Stores a response of `size` bytes when it carries validators and fits the budget
+    pub fn store(
+        &self,
+        url: &str,
+        etag: Option<HeaderValue>,
+        last_modified: Option<HeaderValue>,
+        body: Arc<Value>,
+        size: usize,
+    ) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.remove(url);
+        if (etag.is_none() && last_modified.is_none()) || size > self.max_bytes {
+            return;
+        }
+
+        while entries.bytes + size > self.max_bytes {
+            entries.evict_oldest();
+        }
+        entries.bytes += size;
+        entries.responses.insert(
+            url.to_string(),
+            CachedResponse {
+                etag,
+                last_modified,
+                body,
+                size,
+                last_used: Instant::now(),
+            },
+        );
+    }
+
+    /// Drops all responses (they may belong to another server or API key)
+    pub fn clear(&self) {
+        *self.entries.lock().unwrap() = Entries::default();
+    }
+
+    fn len(&self) -> usize {
+        self.entries.lock().unwrap().responses.len()
+    }
+
+    fn bytes(&self) -> usize {
+        self.entries.lock().unwrap().bytes
+    }
+}
+
+/// Requests currently on the network keyed by URL
+#[derive(Default)]
+pub struct InFlight {
+    requests: Mutex<HashMap<String, Arc<OnceCell<SharedResponse>>>>,
+}
+
+impl InFlight {
+    /// Joins the request for the URL (a new one is registered when none is running)
+    pub fn join(&self, url: &str) -> Arc<OnceCell<SharedResponse>> {
+        let mut requests = self.requests.lock().unwrap();
+        Arc::clone(requests.entry(url.to_string()).or_default())
+    }
+
+    /// Unregisters the finished request so later calls go to the network again
+    pub fn finish(&self, url: &str, cell: &Arc<OnceCell<SharedResponse>>) {
+        let mut requests = self.requests.lock().unwrap();
+        if requests
+            .get(url)
+            .is_some_and(|current| Arc::ptr_eq(current, cell))
+        {
+            requests.remove(url);
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.requests.lock().unwrap().len()
+    }
+}
+
+/// Request counters since app start
+#[derive(Default)]
+pub struct Metrics {
+    requests: AtomicU64,
+    coalesced: AtomicU64,
+    not_modified: AtomicU64,
+    misses: AtomicU64,
+    errors: AtomicU64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Outcome {
+    /// Shared the result of an identical in-flight request
+    Coalesced,
+    /// Server answered 304, cached body reused
+    NotModified,
+    /// Full response downloaded
+    Miss,
+    Error,
+}
+
+impl Metrics {
+    pub fn record(&self, outcome: Outcome) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        let counter = match outcome {
+            Outcome::Coalesced => &self.coalesced,
+            Outcome::NotModified => &self.not_modified,
+            Outcome::Miss => &self.misses,
+            Outcome::Error => &self.errors,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// API layer diagnostics as sent to the UI
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiDiagnostics {
+    pub requests: u64,
+    /// Coalesced + not modified
+    pub hits: u64,
+    pub coalesced: u64,
+    pub not_modified: u64,
+    pub misses: u64,
+    pub errors: u64,
+    /// Share of requests served without downloading the body (0-1)
+    pub hit_rate: f64,
+    pub cached_responses: usize,
+    /// Size of the cached responses as received
+    pub cached_bytes: usize,
+    pub in_flight: usize,
+    /// Problems the app recovered from, newest first
+    pub notices: Vec<Notice>,
+    /// Response fields that no longer match the expected schema
+    pub schema_drift: Vec<DriftIssue>,
+}
+
+/// Returns request coalescing and revalidation counters and recovered problems
+#[tauri::command]
+pub fn get_api_diagnostics(api: State<'_, ApiClient>) -> ApiDiagnostics {
+    let metrics = api.metrics();
+    let requests = metrics.requests.load(Ordering::Relaxed);
+    let coalesced = metrics.coalesced.load(Ordering::Relaxed);
+    let not_modified = metrics.not_modified.load(Ordering::Relaxed);
+    let hits = coalesced + not_modified;
+
+    ApiDiagnostics {
+        requests,
+        hits,
+        coalesced,
+        not_modified,
+        misses: metrics.misses.load(Ordering::Relaxed),
+        errors: metrics.errors.load(Ordering::Relaxed),
+        hit_rate: if requests == 0 {
+            0.0
+        } else {
+            hits as f64 / requests as f64
+        },
+        cached_responses: api.cache().len(),
+        cached_bytes: api.cache().bytes(),
+        in_flight: api.in_flight().len(),
+        notices: api.notices().list(),
+        schema_drift: api.drift().report(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn etag() -> Option<HeaderValue> {
+        Some(HeaderValue::from_static("\"v1\""))
+    }
+
+    fn store(cache: &ResponseCache, url: &str, size: usize) {
+        cache.store(url, etag(), None, Arc::new(json!(url)), size);
+    }
+
+    #[test]
+    fn evicts_least_recently_used_over_the_byte_limit() {
+        let cache = ResponseCache::with_limit(100);
+        store(&cache, "a", 40);
+        store(&cache, "b", 40);
+        // Revalidation marks `a` as used, so `b` goes first
+        assert!(cache.revalidated("a").is_some());
+        store(&cache, "c", 40);
+
+        assert!(cache.peek("a").is_some());
+        assert!(cache.peek("b").is_none());
+        assert!(cache.peek("c").is_some());
+        assert_eq!(cache.bytes(), 80);
+    }
+
+    #[test]
+    fn replaces_responses_and_skips_uncacheable_ones() {
+        let cache = ResponseCache::with_limit(100);
+        store(&cache, "a", 40);
+        store(&cache, "a", 30);
+        assert_eq!((cache.len(), cache.bytes()), (1, 30));
+
+        // Larger than the whole budget
+        store(&cache, "b", 101);
+        assert!(cache.peek("b").is_none());
+
+        // No validators: the stale entry is dropped
+        cache.store("a", None, None, Arc::new(json!(1)), 10);
+        assert_eq!((cache.len(), cache.bytes()), (0, 0));
+    }
+
+    #[test]
+    fn adds_validators_of_cached_responses() {
+        let cache = ResponseCache::default();
+        let mut headers = HeaderMap::new();
+        assert!(!cache.conditional_headers("a", &mut headers));
+
+        store(&cache, "a", 10);
+        assert!(cache.conditional_headers("a", &mut headers));
+        assert_eq!(headers[IF_NONE_MATCH], "\"v1\"");
+        assert!(!headers.contains_key(IF_MODIFIED_SINCE));
+    }
+
+    #[test]
+    fn clear_drops_everything() {
+        let cache = ResponseCache::default();
+        store(&cache, "a", 10);
+        store(&cache, "b", 10);
+
+        cache.clear();
+
+        assert_eq!((cache.len(), cache.bytes()), (0, 0));
+        assert!(cache.peek("a").is_none());
+    }
+}
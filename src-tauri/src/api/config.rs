@@ -0,0 +1,95 @@
+//! API connection settings (base URL, API key, user agent, timeouts, proxy,
+//! patch notes feed)
+//!
+//! Stored in the `api` section of the settings (see `crate::settings`) and
+//! reapplied to the shared client when they change.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::BASE_URL;
+use crate::error::AppError;
+
+/// Header carrying the API key (sent to the API only)
+pub const API_KEY_HEADER: &str = "X-API-Key";
+
+const DEFAULT_USER_AGENT: &str = concat!("DeadLyze/", env!("CARGO_PKG_VERSION"));
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 10;
+
+/// Connection settings; unset fields use the defaults
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ApiSettings {
+    /// API base URL (self-hosted mirror or local stand-in)
+    pub base_url: Option<String>,
+    pub api_key: Option<String>,
+    pub user_agent: Option<String>,
+    /// Whole request timeout
+    pub timeout_secs: Option<u64>,
+    pub connect_timeout_secs: Option<u64>,
+    /// HTTP(S) or SOCKS5 proxy URL (`http://`, `https://`, `socks5://`)
+    pub proxy: Option<String>,
+    /// Patch notes RSS feed used while the API is down (URL or local file;
+    /// the official forum feed when unset)
+    pub patch_feed: Option<String>,
+}
+
+/// Non-empty trimmed value of an optional setting
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+impl ApiSettings {
+    /// Base URL without trailing slash
+    pub fn base_url(&self) -> Result<String, AppError> {
+        let Some(base_url) = non_empty(&self.base_url) else {
+            return Ok(BASE_URL.to_string());
+        };
+
+        let lower = base_url.to_ascii_lowercase();
+        if !(lower.starts_with("http://") || lower.starts_with("https://")) {
+            return Err(AppError::invalid_settings(
+                "base_url",
+                format!("expected http(s) URL, got {}", base_url),
+            ));
+        }
+
+        Ok(base_url.trim_end_matches('/').to_string())
+    }
+
+    pub fn api_key(&self) -> Option<String> {
+        non_empty(&self.api_key).map(str::to_string)
+    }
+
+    pub fn patch_feed(&self) -> Option<&str> {
+        non_empty(&self.patch_feed)
+    }
+
+    /// Builds HTTP client with user agent, timeouts and proxy applied
+    pub fn build_http(&self) -> Result<reqwest::Client, AppError> {
+        let mut builder = reqwest::Client::builder()
+            .user_agent(non_empty(&self.user_agent).unwrap_or(DEFAULT_USER_AGENT))
+            .timeout(Duration::from_secs(
+                self.timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS),
+            ))
+            .connect_timeout(Duration::from_secs(
+                self.connect_timeout_secs
+                    .unwrap_or(DEFAULT_CONNECT_TIMEOUT_SECS),
+            ));
+
+        if let Some(proxy) = non_empty(&self.proxy) {
+            let proxy =
+                reqwest::Proxy::all(proxy).map_err(|e| AppError::invalid_settings("proxy", e))?;
+            builder = builder.proxy(proxy);
+        }
+
+        builder
+            .build()
+            .map_err(|e| AppError::invalid_settings("client", e))
+    }
+}
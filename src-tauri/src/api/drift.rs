@@ -0,0 +1,348 @@
+//! Lenient response decoding and schema drift reporting
+//!
+//! A field with an unexpected type is dropped and falls back to its default
+//! instead of failing the whole response. Unknown fields and type mismatches
+//! are collected per endpoint and reported by the diagnostics command.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use serde_path_to_error::{Segment, Track};
+use tauri::State;
+
+use super::ApiClient;
+
+/// Upper bound of dropped fields per response before giving up
+const MAX_REPAIRS: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DriftKind {
+    /// Field not known to the response struct (ignored)
+    UnknownField,
+    /// Field of a different type than expected (replaced by default)
+    TypeMismatch,
+}
+
+/// Single drift found while decoding a response
+#[derive(Debug, Clone)]
+pub struct Drift {
+    /// Field path with array indices collapsed (`[].division`)
+    pub path: String,
+    pub kind: DriftKind,
+    /// Deserializer message for type mismatches
+    pub detail: Option<String>,
+}
+
+/// Aggregated drift of an endpoint field as sent to the UI
+#[derive(Debug, Clone, Serialize)]
+pub struct DriftIssue {
+    /// Endpoint with numeric path segments replaced by `{id}`
+    pub endpoint: String,
+    pub path: String,
+    pub kind: DriftKind,
+    /// Latest deserializer message
+    pub detail: Option<String>,
+    pub first_seen: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+    pub occurrences: u64,
+}
+
+/// Drift collected over the app session (part of `ApiClient`)
+#[derive(Default)]
+pub struct DriftReporter {
+    issues: Mutex<HashMap<(String, String, DriftKind), DriftIssue>>,
+}
+
+impl DriftReporter {
+    /// Records drifts of a response
+    pub fn record(&self, endpoint: &str, drifts: Vec<Drift>) {
+        if drifts.is_empty() {
+            return;
+        }
+
+        let endpoint = normalize_endpoint(endpoint);
+        let now = Utc::now();
+        let mut issues = self.issues.lock().unwrap();
+
+        for drift in drifts {
+            let key = (endpoint.clone(), drift.path.clone(), drift.kind);
+            match issues.get_mut(&key) {
+                Some(issue) => {
+                    issue.last_seen = now;
+                    issue.occurrences += 1;
+                    if drift.detail.is_some() {
+                        issue.detail = drift.detail;
+                    }
+                }
+                None => {
+                    issues.insert(
+                        key,
+                        DriftIssue {
+                            endpoint: endpoint.clone(),
+                            path: drift.path,
+                            kind: drift.kind,
+                            detail: drift.detail,
+                            first_seen: now,
+                            last_seen: now,
+                            occurrences: 1,
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    /// All issues sorted by endpoint and field path
+    pub fn report(&self) -> Vec<DriftIssue> {
+        let mut report: Vec<DriftIssue> = self.issues.lock().unwrap().values().cloned().collect();
+        report.sort_by(|a, b| (&a.endpoint, &a.path).cmp(&(&b.endpoint, &b.path)));
+        report
+    }
+}
+
+/// Replaces numeric path segments (`/v1/players/123/mate-stats` -> `/v1/players/{id}/mate-stats`)
+fn normalize_endpoint(endpoint: &str) -> String {
+    endpoint
+        .split('/')
+        .map(|segment| {
+            if !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()) {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn push_segment(path: &mut String, key: Option<&str>) {
+    match key {
+        Some(key) => {
+            if !path.is_empty() {
+                path.push('.');
+            }
+            path.push_str(key);
+        }
+        None => path.push_str("[]"),
+    }
+}
+
+fn ignored_path(path: &serde_ignored::Path) -> String {
+    use serde_ignored::Path;
+
+    match path {
+        Path::Root => String::new(),
+        Path::Seq { parent, .. } => {
+            let mut result = ignored_path(parent);
+            push_segment(&mut result, None);
+            result
+        }
+        Path::Map { parent, key } => {
+            let mut result = ignored_path(parent);
+            push_segment(&mut result, Some(key));
+            result
+        }
+        Path::Some { parent }
+        | Path::NewtypeStruct { parent }
+        | Path::NewtypeVariant { parent } => ignored_path(parent),
+    }
+}
+
+fn error_path(path: &serde_path_to_error::Path) -> String {
+    let mut result = String::new();
+    for segment in path {
+        match segment {
+            Segment::Seq { .. } => push_segment(&mut result, None),
+            Segment::Map { key } => push_segment(&mut result, Some(key)),
+            Segment::Enum { .. } | Segment::Unknown => {}
+        }
+    }
+    result
+}
+
+/// Value at the exact error path
+fn value_at<'a>(value: &'a Value, segments: &[&Segment]) -> Option<&'a Value> {
+    segments
+        .iter()
+        .try_fold(value, |current, segment| match (segment, current) {
+            (Segment::Seq { index }, Value::Array(items)) => items.get(*index),
+            (Segment::Map { key }, Value::Object(fields)) => fields.get(key),
+            _ => None,
+        })
+}
+
+/// Removes the field from every array element where it has the same JSON type
+/// as the mistyped value, so a systematic change is repaired in one pass
+fn remove_matching(value: &mut Value, segments: &[&Segment], found: &Value) -> usize {
+    match (segments, value) {
+        ([Segment::Map { key }], Value::Object(fields)) => {
+            let same_type = fields.get(key).is_some_and(|field| {
+                std::mem::discriminant(field) == std::mem::discriminant(found)
+            });
+            if same_type {
+                fields.remove(key);
+                1
+            } else {
+                0
+            }
+        }
+        ([Segment::Seq { .. }, rest @ ..], Value::Array(items)) => items
+            .iter_mut()
+            .map(|item| remove_matching(item, rest, found))
+            .sum(),
+        ([Segment::Map { key }, rest @ ..], Value::Object(fields)) => fields
+            .get_mut(key)
+            .map_or(0, |field| remove_matching(field, rest, found)),
+        _ => 0,
+    }
+}
+
+/// Drops the mistyped field; false when the path does not end at an object field
+fn remove_field(value: &mut Value, path: &serde_path_to_error::Path) -> bool {
+    let segments: Vec<&Segment> = path.iter().collect();
+    if !matches!(segments.last(), Some(Segment::Map { .. })) {
+        return false;
+    }
+
+    match value_at(value, &segments).cloned() {
+        Some(found) => remove_matching(value, &segments, &found) > 0,
+        None => false,
+    }
+}
+
+/// Decodes JSON leniently, dropping mistyped fields until the struct fits
+///
+/// The value is only copied when a field has to be dropped.
+pub fn decode_lenient<T: DeserializeOwned>(value: &Value) -> Result<(T, Vec<Drift>), String> {
+    let mut value = Cow::Borrowed(value);
+    let mut drifts = Vec::new();
+
+    for _ in 0..=MAX_REPAIRS {
+        let mut track = Track::new();
+        let mut unknown = Vec::new();
+        let result: Result<T, _> = serde_ignored::deserialize(
+            serde_path_to_error::Deserializer::new(&*value, &mut track),
+            |path| unknown.push(ignored_path(&path)),
+        );
+
+        let error = match result {
+            Ok(parsed) => {
+                // Array elements collapse to the same path
+                unknown.sort();
+                unknown.dedup();
+                drifts.extend(unknown.into_iter().map(|path| Drift {
+                    path,
+                    kind: DriftKind::UnknownField,
+                    detail: None,
+                }));
+                return Ok((parsed, drifts));
+            }
+            Err(e) => e,
+        };
+
+        let path = track.path();
+        if !remove_field(value.to_mut(), &path) {
+            // Report the original mismatch rather than a follow-up missing field
+            return Err(drifts
+                .into_iter()
+                .find_map(|drift| drift.detail)
+                .unwrap_or_else(|| error.to_string()));
+        }
+
+        drifts.push(Drift {
+            path: error_path(&path),
+            kind: DriftKind::TypeMismatch,
+            detail: Some(error.to_string()),
+        });
+    }
+
+    Err(format!("more than {} mismatched fields", MAX_REPAIRS))
+}
+
+/// Returns schema drift seen in API responses during this session
+#[tauri::command]
+pub fn get_schema_drift(api: State<'_, ApiClient>) -> Vec<DriftIssue> {
+    api.drift().report()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct Rank {
+        account_id: u32,
+        #[serde(default)]
+        division: u32,
+    }
+
+    #[test]
+    fn drops_mistyped_fields_across_array_elements() {
+        let value = json!([
+            { "account_id": 1, "division": "gold" },
+            { "account_id": 2, "division": "silver", "badge": 7 },
+        ]);
+
+        let (ranks, drifts) = decode_lenient::<Vec<Rank>>(&value).unwrap();
+
+        assert_eq!(ranks.len(), 2);
+        assert_eq!(ranks[1].account_id, 2);
+        assert_eq!(ranks[1].division, 0);
+        let found: Vec<_> = drifts
+            .iter()
+            .map(|drift| (drift.path.as_str(), drift.kind))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("[].division", DriftKind::TypeMismatch),
+                ("[].badge", DriftKind::UnknownField),
+            ]
+        );
+    }
+
+    #[test]
+    fn fails_when_a_required_field_is_mistyped_or_missing() {
+        let error = decode_lenient::<Rank>(&json!({ "account_id": "7" })).unwrap_err();
+        assert!(error.contains("invalid type"), "{}", error);
+
+        assert!(decode_lenient::<Rank>(&json!({ "division": 3 })).is_err());
+    }
+
+    #[test]
+    fn removes_only_fields_of_the_mistyped_type() {
+        let mut value = json!([
+            { "division": "gold" },
+            { "division": 3 },
+            { "division": "silver" },
+        ]);
+        let segments = [
+            &Segment::Seq { index: 0 },
+            &Segment::Map {
+                key: "division".to_string(),
+            },
+        ];
+
+        let removed = remove_matching(&mut value, &segments, &json!("gold"));
+
+        assert_eq!(removed, 2);
+        assert_eq!(value, json!([{}, { "division": 3 }, {}]));
+    }
+
+    #[test]
+    fn normalizes_numeric_path_segments() {
+        assert_eq!(
+            normalize_endpoint("/v1/players/123/mate-stats"),
+            "/v1/players/{id}/mate-stats"
+        );
+    }
+}
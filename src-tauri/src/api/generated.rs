@@ -0,0 +1,14 @@
+//! Models and endpoint methods generated from the vendored OpenAPI spec
+//! (`openapi/deadlock-api.json`, see `build/openapi.rs`)
+//!
+//! New endpoints are added to the spec; the app then calls the generated
+//! `ApiClient` method instead of building requests by hand.
+
+#![allow(dead_code)]
+
+use serde::{Deserialize, Serialize};
+
+use super::ApiClient;
+use crate::error::AppError;
+
+include!(concat!(env!("OUT_DIR"), "/deadlock_api.rs"));
@@ -0,0 +1,350 @@
+//! HTTP client for the Deadlock API (https://api.deadlock-api.com)
+
+pub mod cache;
+pub mod config;
+pub mod drift;
+pub mod generated;
+pub mod notices;
+#[cfg(test)]
+pub mod test_server;
+
+use std::sync::{Arc, RwLock};
+
+use reqwest::header::{HeaderValue, InvalidHeaderValue, ETAG, LAST_MODIFIED};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use cache::{InFlight, Metrics, Outcome, ResponseCache, SharedResponse};
+use config::{ApiSettings, API_KEY_HEADER};
+use drift::DriftReporter;
+use notices::Notices;
+
+use crate::error::AppError;
+
+/// API base URL
+pub const BASE_URL: &str = "https://api.deadlock-api.com";
+
+/// Notice source of API settings replaced by defaults
+const SETTINGS_NOTICE: &str = "api_settings";
+
+/// Client state replaced as a whole when settings change
+struct Connection {
+    http: reqwest::Client,
+    base_url: String,
+    api_key: Option<HeaderValue>,
+    patch_feed: Option<String>,
+}
+
+impl Connection {
+    fn from_settings(settings: &ApiSettings) -> Result<Self, AppError> {
+        let api_key = settings
+            .api_key()
+            .map(|key| {
+                let mut value = HeaderValue::from_str(&key)?;
+                value.set_sensitive(true);
+                Ok(value)
+            })
+            .transpose()
+            .map_err(|e: InvalidHeaderValue| AppError::invalid_settings("api_key", e))?;
+
+        Ok(Self {
+            http: settings.build_http()?,
+            base_url: settings.base_url()?,
+            api_key,
+            patch_feed: settings.patch_feed().map(str::to_string),
+        })
+    }
+}
+
+/// Shared Deadlock API client (managed as Tauri state)
+pub struct ApiClient {
+    connection: RwLock<Connection>,
+    drift: DriftReporter,
+    notices: Notices,
+    cache: ResponseCache,
+    in_flight: InFlight,
+    metrics: Metrics,
+}
+
+impl ApiClient {
+    /// Creates client from settings; invalid settings fall back to defaults
+    /// (reported as a notice)
+    pub fn new(settings: &ApiSettings) -> Self {
+        let notices = Notices::default();
+        let connection = Connection::from_settings(settings).unwrap_or_else(|e| {
+            notices.record(SETTINGS_NOTICE, e);
+            Connection::from_settings(&ApiSettings::default())
+                .expect("default API settings are valid")
+        });
+
+        Self {
+            connection: RwLock::new(connection),
+            drift: DriftReporter::default(),
+            notices,
+            cache: ResponseCache::default(),
+            in_flight: InFlight::default(),
+            metrics: Metrics::default(),
+        }
+    }
+
+    /// Replaces connection settings (in-flight requests finish with the old ones)
+    ///
+    /// Cached responses are dropped, as they came from the old server or key.
+    pub fn configure(&self, settings: &ApiSettings) -> Result<(), AppError> {
+        let connection = Connection::from_settings(settings)?;
+        *self.connection.write().unwrap() = connection;
+        self.cache.clear();
+        Ok(())
+    }
+
+    /// Underlying HTTP client (for non-API resources; the API key is not attached)
+    pub fn http(&self) -> reqwest::Client {
+        self.connection.read().unwrap().http.clone()
+    }
+
+    /// Patch notes feed location from the settings (the official feed when unset)
+    pub fn patch_feed(&self) -> Option<String> {
+        self.connection.read().unwrap().patch_feed.clone()
+    }
+
+    /// Schema drift seen in responses
+    pub fn drift(&self) -> &DriftReporter {
+        &self.drift
+    }
+
+    /// Problems the app recovered from
+    pub fn notices(&self) -> &Notices {
+        &self.notices
+    }
+
+    pub fn cache(&self) -> &ResponseCache {
+        &self.cache
+    }
+
+    pub fn in_flight(&self) -> &InFlight {
+        &self.in_flight
+    }
+
+    pub fn metrics(&self) -> &Metrics {
+        &self.metrics
+    }
+
+    /// Performs GET request to the endpoint and deserializes JSON response
+    ///
+    /// Identical concurrent requests share one network call and cached responses
+    /// are revalidated. Mistyped fields fall back to defaults and are reported
+    /// as schema drift.
+    pub async fn get<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        query: &[(&str, String)],
+    ) -> Result<T, AppError> {
+        let (http, request) = self.request(endpoint, query)?;
+
+        let url = request.url().to_string();
+        let shared = self.in_flight.join(&url);
+        let mut leader = false;
+        let result = shared
+            .get_or_init(|| {
+                leader = true;
+                self.fetch(endpoint, http, request)
+            })
+            .await
+            .clone();
+        self.in_flight.finish(&url, &shared);
+
+        if !leader {
+            self.metrics.record(Outcome::Coalesced);
+        }
+
+        let body = result?;
+        let (parsed, drifts) =
+            drift::decode_lenient(&body).map_err(|e| AppError::invalid_response(endpoint, e))?;
+        self.drift.record(endpoint, drifts);
+
+        Ok(parsed)
+    }
+
+    /// Last cached response of the request, without going to the network
+    ///
+    /// Used as a fallback while the API is unavailable.
+    pub fn get_cached<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        query: &[(&str, String)],
+    ) -> Option<T> {
+        let (_, request) = self.request(endpoint, query).ok()?;
+        let body = self.cache.peek(request.url().as_str())?;
+        drift::decode_lenient(&body).ok().map(|(parsed, _)| parsed)
+    }
+
+    /// Builds GET request with the API key attached
+    fn request(
+        &self,
+        endpoint: &str,
+        query: &[(&str, String)],
+    ) -> Result<(reqwest::Client, reqwest::Request), AppError> {
+        let connection = self.connection.read().unwrap();
+        let mut request = connection
+            .http
+            .get(format!("{}{}", connection.base_url, endpoint));
+        if let Some(api_key) = &connection.api_key {
+            request = request.header(API_KEY_HEADER, api_key.clone());
+        }
+        let request = request
+            .query(query)
+            .build()
+            .map_err(|e| AppError::network(endpoint, e))?;
+        Ok((connection.http.clone(), request))
+    }
+
+    /// Sends the request (conditional when a cached response exists)
+    async fn fetch(
+        &self,
+        endpoint: &str,
+        http: reqwest::Client,
+        mut request: reqwest::Request,
+    ) -> SharedResponse {
+        let url = request.url().to_string();
+        let conditional = self.cache.conditional_headers(&url, request.headers_mut());
+
+        let response = match http.execute(request).await {
+            Ok(response) => response,
+            Err(e) => {
+                self.metrics.record(Outcome::Error);
+                return Err(AppError::network(&url, e));
+            }
+        };
+        let status = response.status();
+
+        if conditional && status == StatusCode::NOT_MODIFIED {
+            if let Some(body) = self.cache.revalidated(&url) {
+                self.metrics.record(Outcome::NotModified);
+                return Ok(body);
+            }
+        }
+
+        if !status.is_success() {
+            self.metrics.record(Outcome::Error);
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(AppError::from_api_status(
+                endpoint,
+                status.as_u16(),
+                error_text,
+            ));
+        }
+
+        let etag = response.headers().get(ETAG).cloned();
+        let last_modified = response.headers().get(LAST_MODIFIED).cloned();
+        let bytes = match response.bytes().await {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                self.metrics.record(Outcome::Error);
+                return Err(AppError::network(&url, e));
+            }
+        };
+        let body = match serde_json::from_slice::<Value>(&bytes) {
+            Ok(body) => Arc::new(body),
+            Err(e) => {
+                self.metrics.record(Outcome::Error);
+                return Err(AppError::invalid_response(endpoint, e));
+            }
+        };
+
+        self.metrics.record(Outcome::Miss);
+        self.cache
+            .store(&url, etag, last_modified, Arc::clone(&body), bytes.len());
+        Ok(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use super::*;
+    use test_server::{Reply, TestServer};
+
+    #[test]
+    fn concurrent_identical_requests_share_one_fetch() {
+        let server = TestServer::start(|_| {
+            std::thread::sleep(Duration::from_millis(200));
+            Reply::json(r#"{"value":1}"#)
+        });
+        let api = server.client();
+
+        let (first, second) = tauri::async_runtime::block_on(async {
+            tokio::join!(
+                api.get::<Value>("/v1/shared", &[]),
+                api.get::<Value>("/v1/shared", &[])
+            )
+        });
+
+        assert_eq!(first.unwrap()["value"], 1);
+        assert_eq!(second.unwrap()["value"], 1);
+        assert_eq!(server.requests(), ["/v1/shared"]);
+    }
+
+    #[test]
+    fn failed_requests_are_not_shared_with_later_calls() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let server = TestServer::start({
+            let calls = Arc::clone(&calls);
+            move |_| match calls.fetch_add(1, Ordering::SeqCst) {
+                0 => Reply::status(500),
+                _ => Reply::json(r#"{"value":2}"#),
+            }
+        });
+        let api = server.client();
+
+        tauri::async_runtime::block_on(async {
+            assert!(api.get::<Value>("/v1/flaky", &[]).await.is_err());
+            let retried = api.get::<Value>("/v1/flaky", &[]).await.unwrap();
+            assert_eq!(retried["value"], 2);
+        });
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[test]
+    fn revalidates_until_settings_change() {
+        let validators = Arc::new(Mutex::new(Vec::new()));
+        let server = TestServer::start({
+            let validators = Arc::clone(&validators);
+            move |request| {
+                let validator = request.header("if-none-match").map(str::to_string);
+                let not_modified = validator.as_deref() == Some("\"v1\"");
+                validators.lock().unwrap().push(validator);
+                if not_modified {
+                    Reply::status(304)
+                } else {
+                    Reply::json(r#"{"value":3}"#).header("ETag", "\"v1\"")
+                }
+            }
+        });
+        let api = server.client();
+
+        tauri::async_runtime::block_on(async {
+            assert_eq!(
+                api.get::<Value>("/v1/cached", &[]).await.unwrap()["value"],
+                3
+            );
+            // 304: the cached body is reused
+            assert_eq!(
+                api.get::<Value>("/v1/cached", &[]).await.unwrap()["value"],
+                3
+            );
+
+            api.configure(&server.settings()).unwrap();
+            assert_eq!(
+                api.get::<Value>("/v1/cached", &[]).await.unwrap()["value"],
+                3
+            );
+        });
+
+        let validator = Some("\"v1\"".to_string());
+        assert_eq!(*validators.lock().unwrap(), [None, validator, None]);
+    }
+}
@@ -0,0 +1,49 @@
+//! Recoverable problems of the API layer and local stores (settings fallback,
+//! patch feed failures, skipped feed items, unreadable data files)
+//!
+//! The release build has no console, so these are kept for the diagnostics
+//! command instead of being printed.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::error::AppError;
+
+/// Newest notices kept (older ones are dropped)
+const MAX_NOTICES: usize = 50;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Notice {
+    /// Subsystem that recovered from the error (e.g. `patch_feed`)
+    pub source: String,
+    pub error: AppError,
+    pub time: DateTime<Utc>,
+}
+
+/// Notices collected over the app session (part of `ApiClient`)
+#[derive(Default)]
+pub struct Notices {
+    entries: Mutex<VecDeque<Notice>>,
+}
+
+impl Notices {
+    pub fn record(&self, source: &str, error: AppError) {
+        let mut entries = self.entries.lock().unwrap();
+        if entries.len() == MAX_NOTICES {
+            entries.pop_front();
+        }
+        entries.push_back(Notice {
+            source: source.to_string(),
+            error,
+            time: Utc::now(),
+        });
+    }
+
+    /// Notices, newest first
+    pub fn list(&self) -> Vec<Notice> {
+        self.entries.lock().unwrap().iter().rev().cloned().collect()
+    }
+}
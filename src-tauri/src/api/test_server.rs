@@ -0,0 +1,139 @@
+//! Local HTTP server standing in for the API in tests
+//!
+//! Each connection is answered on its own thread by the handler, so slow
+//! replies overlap like real network calls.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+
+use super::config::ApiSettings;
+use super::ApiClient;
+
+/// Request as seen by the handler
+pub struct Request {
+    /// Path with query string
+    pub target: String,
+    /// Header names in lowercase
+    pub headers: Vec<(String, String)>,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+pub struct Reply {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Reply {
+    pub fn json(body: impl ToString) -> Self {
+        Self {
+            status: 200,
+            headers: Vec::new(),
+            body: body.to_string(),
+        }
+    }
+
+    /// Reply without a body
+    pub fn status(status: u16) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: String::new(),
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+type Handler = dyn Fn(&Request) -> Reply + Send + Sync;
+
+pub struct TestServer {
+    url: String,
+    targets: Arc<Mutex<Vec<String>>>,
+}
+
+impl TestServer {
+    pub fn start(handler: impl Fn(&Request) -> Reply + Send + Sync + 'static) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let targets = Arc::new(Mutex::new(Vec::new()));
+        let handler: Arc<Handler> = Arc::new(handler);
+
+        let received = Arc::clone(&targets);
+        std::thread::spawn(move || {
+            for stream in listener.incoming().map_while(Result::ok) {
+                let handler = Arc::clone(&handler);
+                let received = Arc::clone(&received);
+                std::thread::spawn(move || serve(stream, &*handler, &received));
+            }
+        });
+
+        Self { url, targets }
+    }
+
+    /// Client sending requests to this server
+    pub fn client(&self) -> ApiClient {
+        ApiClient::new(&self.settings())
+    }
+
+    pub fn settings(&self) -> ApiSettings {
+        ApiSettings {
+            base_url: Some(self.url.clone()),
+            ..ApiSettings::default()
+        }
+    }
+
+    /// Targets of the requests received so far
+    pub fn requests(&self) -> Vec<String> {
+        self.targets.lock().unwrap().clone()
+    }
+}
+
+fn serve(stream: TcpStream, handler: &Handler, received: &Mutex<Vec<String>>) {
+    let mut reader = BufReader::new(&stream);
+    let mut line = String::new();
+    if reader.read_line(&mut line).is_err() {
+        return;
+    }
+    let target = line.split_whitespace().nth(1).unwrap_or("/").to_string();
+
+    let mut headers = Vec::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).is_err() || line.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_lowercase(), value.trim().to_string()));
+        }
+    }
+
+    received.lock().unwrap().push(target.clone());
+    let reply = handler(&Request { target, headers });
+
+    let extra_headers: String = reply
+        .headers
+        .iter()
+        .map(|(name, value)| format!("{}: {}\r\n", name, value))
+        .collect();
+    let response = format!(
+        "HTTP/1.1 {} Test\r\nContent-Type: application/json\r\nContent-Length: {}\r\n{}Connection: close\r\n\r\n{}",
+        reply.status,
+        reply.body.len(),
+        extra_headers,
+        reply.body
+    );
+    let _ = (&stream).write_all(response.as_bytes());
+}
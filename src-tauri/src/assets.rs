@@ -0,0 +1,403 @@
+//! Hero and item metadata (names, icons, classes, costs)
+//!
+//! API responses only carry numeric IDs; commands resolve them in the UI
+//! language before returning. Metadata comes from a snapshot bundled into the
+//! binary (works offline) and is refreshed from the assets API; the refreshed
+//! snapshot is stored in the app data directory and takes precedence over the
+//! bundled one. An incomplete snapshot (no shop items or translations) is
+//! refreshed on startup. The stored file has the bundled format, so the
+//! bundled snapshot is regenerated by copying it into `resources`.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, State};
+
+use crate::api::ApiClient;
+use crate::error::AppError;
+use crate::settings::SettingsStore;
+use crate::storage;
+
+/// Snapshot shipped with the app
+const BUNDLED_SNAPSHOT: &str = include_str!("../resources/game_assets.json");
+
+/// Refreshed snapshot file in the app data directory
+pub const ASSETS_FILE: &str = "game_assets.json";
+
+const ASSETS_BASE_URL: &str = "https://assets.deadlock-api.com";
+
+/// App locales and matching assets API languages
+const LANGUAGES: &[(&str, &str)] = &[("en", "english"), ("ru", "russian")];
+
+/// Notice source of failed background refreshes
+const NOTICE: &str = "assets";
+
+/// Fallback locale for missing translations
+const DEFAULT_LANGUAGE: &str = "en";
+
+/// Names per app locale (`en`, `ru`)
+pub type LocalizedNames = HashMap<String, String>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeroAsset {
+    pub id: u32,
+    pub class_name: String,
+    pub names: LocalizedNames,
+    #[serde(default)]
+    pub hero_type: Option<String>,
+    #[serde(default)]
+    pub icon: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemAsset {
+    pub id: u32,
+    pub class_name: String,
+    pub names: LocalizedNames,
+    /// Shop category: `weapon`, `vitality` or `spirit`
+    #[serde(default)]
+    pub slot: Option<String>,
+    #[serde(default)]
+    pub tier: Option<u32>,
+    #[serde(default)]
+    pub cost: Option<u32>,
+    #[serde(default)]
+    pub icon: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AssetSnapshot {
+    /// `None` for the bundled snapshot
+    pub fetched_at: Option<DateTime<Utc>>,
+    pub heroes: Vec<HeroAsset>,
+    pub items: Vec<ItemAsset>,
+}
+
+/// Picks name in the requested locale, falling back to English
+fn localized(names: &LocalizedNames, language: &str) -> Option<String> {
+    names
+        .get(language)
+        .or_else(|| names.get(DEFAULT_LANGUAGE))
+        .cloned()
+}
+
+/// Hero as returned by the assets API (`/v2/heroes`)
+#[derive(Debug, Deserialize)]
+struct RemoteHero {
+    id: u32,
+    class_name: String,
+    name: String,
+    #[serde(default)]
+    hero_type: Option<String>,
+    #[serde(default)]
+    images: HashMap<String, serde_json::Value>,
+}
+
+/// Item as returned by the assets API (`/v2/items`)
+#[derive(Debug, Deserialize)]
+struct RemoteItem {
+    id: u32,
+    class_name: String,
+    name: String,
+    #[serde(rename = "type", default)]
+    item_type: Option<String>,
+    #[serde(default)]
+    item_slot_type: Option<String>,
+    #[serde(default)]
+    item_tier: Option<u32>,
+    #[serde(default)]
+    cost: Option<u32>,
+    #[serde(default)]
+    image: Option<String>,
+}
+
+async fn fetch_remote<T: serde::de::DeserializeOwned>(
+    api: &ApiClient,
+    endpoint: &str,
+    language: &str,
+) -> Result<T, AppError> {
+    let url = format!("{}{}", ASSETS_BASE_URL, endpoint);
+    let response = api
+        .http()
+        .get(&url)
+        .query(&[("language", language)])
+        .send()
+        .await
+        .map_err(|e| AppError::network(&url, e))?;
+    let status = response.status();
+
+    if !status.is_success() {
+        return Err(AppError::Http {
+            url,
+            status: status.as_u16(),
+        });
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| AppError::invalid_response(&url, e))
+}
+
+/// Downloads heroes and shop items in every app locale
+pub async fn fetch_snapshot(api: &ApiClient) -> Result<AssetSnapshot, AppError> {
+    let mut heroes: Vec<HeroAsset> = Vec::new();
+    let mut items: Vec<ItemAsset> = Vec::new();
+
+    for (locale, language) in LANGUAGES {
+        let remote_heroes: Vec<RemoteHero> = fetch_remote(api, "/v2/heroes", language).await?;
+        for remote in remote_heroes {
+            match heroes.iter_mut().find(|hero| hero.id == remote.id) {
+                Some(hero) => {
+                    hero.names.insert(locale.to_string(), remote.name);
+                }
+                None => heroes.push(HeroAsset {
+                    id: remote.id,
+                    class_name: remote.class_name,
+                    names: HashMap::from([(locale.to_string(), remote.name)]),
+                    hero_type: remote.hero_type,
+                    icon: remote
+                        .images
+                        .get("icon_image_small")
+                        .and_then(|value| value.as_str())
+                        .map(str::to_string),
+                }),
+            }
+        }
+
+        let remote_items: Vec<RemoteItem> = fetch_remote(api, "/v2/items", language).await?;
+        for remote in remote_items {
+            // Only shop items; abilities and weapons share the endpoint
+            if remote.item_type.as_deref() != Some("upgrade") {
+                continue;
+            }
+            match items.iter_mut().find(|item| item.id == remote.id) {
+                Some(item) => {
+                    item.names.insert(locale.to_string(), remote.name);
+                }
+                None => items.push(ItemAsset {
+                    id: remote.id,
+                    class_name: remote.class_name,
+                    names: HashMap::from([(locale.to_string(), remote.name)]),
+                    slot: remote.item_slot_type,
+                    tier: remote.item_tier,
+                    cost: remote.cost,
+                    icon: remote.image,
+                }),
+            }
+        }
+    }
+
+    heroes.sort_by_key(|hero| hero.id);
+    items.sort_by_key(|item| item.id);
+
+    Ok(AssetSnapshot {
+        fetched_at: Some(Utc::now()),
+        heroes,
+        items,
+    })
+}
+
+/// Hero or item reference resolved for a locale
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolvedAsset {
+    pub id: u32,
+    pub name: String,
+    pub icon: Option<String>,
+}
+
+/// Resolved IDs as sent to the UI
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ResolvedAssets {
+    pub heroes: HashMap<u32, ResolvedAsset>,
+    pub items: HashMap<u32, ResolvedAsset>,
+}
+
+/// Asset metadata store (managed as Tauri state)
+pub struct Assets {
+    path: PathBuf,
+    snapshot: RwLock<AssetSnapshot>,
+}
+
+impl Assets {
+    /// Loads refreshed snapshot from disk, falling back to the bundled one
+    pub fn load(path: PathBuf) -> Self {
+        let snapshot = storage::load_json::<Option<AssetSnapshot>>(&path)
+            .ok()
+            .flatten()
+            .filter(|snapshot| !snapshot.heroes.is_empty())
+            .unwrap_or_else(|| {
+                serde_json::from_str(BUNDLED_SNAPSHOT).expect("bundled asset snapshot is valid")
+            });
+
+        Self {
+            path,
+            snapshot: RwLock::new(snapshot),
+        }
+    }
+
+    pub fn snapshot(&self) -> AssetSnapshot {
+        self.snapshot.read().unwrap().clone()
+    }
+
+    /// Snapshot lacks shop items or names in one of the app locales
+    pub fn is_incomplete(&self) -> bool {
+        let snapshot = self.snapshot.read().unwrap();
+        let translated = |names: &LocalizedNames| {
+            LANGUAGES
+                .iter()
+                .all(|(locale, _)| names.contains_key(*locale))
+        };
+        snapshot.items.is_empty() || !snapshot.heroes.iter().all(|hero| translated(&hero.names))
+    }
+
+    /// Hero name and icon in the locale (`None` for unknown heroes)
+    pub fn hero(&self, id: u32, language: &str) -> Option<ResolvedAsset> {
+        let snapshot = self.snapshot.read().unwrap();
+        let hero = snapshot.heroes.iter().find(|hero| hero.id == id)?;
+        Some(ResolvedAsset {
+            id,
+            name: localized(&hero.names, language)?,
+            icon: hero.icon.clone(),
+        })
+    }
+
+    /// Item name and icon in the locale (`None` for unknown items)
+    pub fn item(&self, id: u32, language: &str) -> Option<ResolvedAsset> {
+        let snapshot = self.snapshot.read().unwrap();
+        let item = snapshot.items.iter().find(|item| item.id == id)?;
+        Some(ResolvedAsset {
+            id,
+            name: localized(&item.names, language)?,
+            icon: item.icon.clone(),
+        })
+    }
+
+    /// Replaces snapshot and persists it
+    pub fn replace(&self, snapshot: AssetSnapshot) -> Result<(), AppError> {
+        storage::save_json(&self.path, &snapshot)?;
+        *self.snapshot.write().unwrap() = snapshot;
+        Ok(())
+    }
+
+    /// Resolves hero and item IDs (unknown IDs are left out)
+    pub fn resolve(&self, hero_ids: &[u32], item_ids: &[u32], language: &str) -> ResolvedAssets {
+        ResolvedAssets {
+            heroes: hero_ids
+                .iter()
+                .filter_map(|&id| Some((id, self.hero(id, language)?)))
+                .collect(),
+            items: item_ids
+                .iter()
+                .filter_map(|&id| Some((id, self.item(id, language)?)))
+                .collect(),
+        }
+    }
+}
+
+/// Returns full hero and item metadata
+#[tauri::command]
+pub fn get_assets(assets: State<'_, Assets>) -> AssetSnapshot {
+    assets.snapshot()
+}
+
+/// Resolves hero and item IDs into localized names and icons (in the UI
+/// language unless given)
+#[tauri::command]
+pub fn resolve_assets(
+    assets: State<'_, Assets>,
+    settings: State<'_, SettingsStore>,
+    hero_ids: Vec<u32>,
+    item_ids: Vec<u32>,
+    language: Option<String>,
+) -> ResolvedAssets {
+    let language = language.unwrap_or_else(|| settings.get().language);
+    assets.resolve(&hero_ids, &item_ids, &language)
+}
+
+/// Downloads fresh metadata from the assets API
+#[tauri::command]
+pub async fn refresh_assets(
+    api: State<'_, ApiClient>,
+    assets: State<'_, Assets>,
+) -> Result<AssetSnapshot, AppError> {
+    let snapshot = fetch_snapshot(&api).await?;
+    assets.replace(snapshot.clone())?;
+    Ok(snapshot)
+}
+
+/// Refreshes an incomplete snapshot in the background (failures become notices)
+pub fn refresh_if_incomplete(app: &AppHandle) {
+    if !app.state::<Assets>().is_incomplete() {
+        return;
+    }
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let api = app.state::<ApiClient>();
+        let result = match fetch_snapshot(&api).await {
+            Ok(snapshot) => app.state::<Assets>().replace(snapshot),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            api.notices().record(NOTICE, e);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bundled() -> Assets {
+        Assets::load(std::env::temp_dir().join("deadlyze-missing-assets.json"))
+    }
+
+    #[test]
+    fn bundled_snapshot_is_complete() {
+        let assets = bundled();
+
+        assert!(!assets.is_incomplete());
+        let snapshot = assets.snapshot();
+        assert!(snapshot
+            .items
+            .iter()
+            .all(|item| item.slot.is_some() && item.tier.is_some() && item.cost.is_some()));
+    }
+
+    #[test]
+    fn resolves_bundled_heroes_with_english_fallback() {
+        let assets = bundled();
+
+        assert_eq!(assets.hero(1, "ru").unwrap().name, "Инфернус");
+        assert_eq!(assets.hero(1, "de").unwrap().name, "Infernus");
+        assert!(assets.hero(u32::MAX, "en").is_none());
+
+        let resolved = assets.resolve(&[1, u32::MAX], &[], "en");
+        assert_eq!(resolved.heroes.len(), 1);
+    }
+
+    #[test]
+    fn resolves_bundled_items() {
+        let assets = bundled();
+        let id = assets
+            .snapshot()
+            .items
+            .iter()
+            .find(|item| item.class_name == "upgrade_clip_size")
+            .unwrap()
+            .id;
+
+        let item = assets.item(id, "en").unwrap();
+        assert_eq!(item.name, "Extended Magazine");
+        assert!(item.icon.is_some());
+        assert_eq!(assets.item(id, "ru").unwrap().name, "Увеличенный магазин");
+
+        let resolved = assets.resolve(&[], &[id, u32::MAX], "ru");
+        assert_eq!(resolved.items.len(), 1);
+        assert_eq!(resolved.items[&id].name, "Увеличенный магазин");
+    }
+}
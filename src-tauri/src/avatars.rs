@@ -0,0 +1,377 @@
+//! Steam avatar cache served through `deadlyze-img://` protocol
+//!
+//! URL format: `deadlyze-img://avatar/<account_id>/<small|medium|full>`
+//! (on Windows the webview addresses it as `http://deadlyze-img.localhost/avatar/...`).
+//! Images are stored in the app cache directory and evicted least-recently-used
+//! first once the cache grows over [`MAX_CACHE_BYTES`]. Stale images are still
+//! served when Steam or the API can't be reached.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use tauri::http::{header, Request, Response, StatusCode, Uri};
+use tauri::{AppHandle, Manager};
+
+use crate::api::ApiClient;
+use crate::error::AppError;
+use crate::players;
+use crate::storage;
+
+/// Custom URI scheme name
+pub const PROTOCOL: &str = "deadlyze-img";
+
+/// Cache size limit (bytes)
+const MAX_CACHE_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Largest avatar accepted from the CDN (Steam's full size is well under it)
+const MAX_AVATAR_BYTES: u64 = 2 * 1024 * 1024;
+
+/// Cached image is refreshed after this age (served as-is when offline)
+const REFRESH_AFTER: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Avatar size as exposed by Steam
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvatarSize {
+    /// 32x32 (`avatar`)
+    Small,
+    /// 64x64 (`avatarmedium`)
+    Medium,
+    /// 184x184 (`avatarfull`)
+    Full,
+}
+
+impl AvatarSize {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "small" => Some(Self::Small),
+            "medium" => Some(Self::Medium),
+            "full" => Some(Self::Full),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Small => "small",
+            Self::Medium => "medium",
+            Self::Full => "full",
+        }
+    }
+
+    fn url(self, profile: &players::SteamProfile) -> &str {
+        match self {
+            Self::Small => &profile.avatar,
+            Self::Medium => &profile.avatarmedium,
+            Self::Full => &profile.avatarfull,
+        }
+    }
+}
+
+/// Protocol URL of the avatar as the webview addresses it
+pub fn avatar_url(account_id: u32, size: AvatarSize) -> String {
+    if cfg!(windows) {
+        format!(
+            "http://{}.localhost/avatar/{}/{}",
+            PROTOCOL,
+            account_id,
+            size.name()
+        )
+    } else {
+        format!("{}://avatar/{}/{}", PROTOCOL, account_id, size.name())
+    }
+}
+
+/// On-disk image cache (managed as Tauri state)
+pub struct ImageCache {
+    dir: PathBuf,
+    max_bytes: u64,
+    /// Serializes writes and eviction
+    write_lock: tokio::sync::Mutex<()>,
+}
+
+impl ImageCache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            max_bytes: MAX_CACHE_BYTES,
+            write_lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    fn avatar_path(&self, account_id: u32, size: AvatarSize) -> PathBuf {
+        self.dir
+            .join(format!("avatar_{}_{}.img", account_id, size.name()))
+    }
+
+    /// Reads cached image and marks it as recently used; returns image with its age
+    async fn read(&self, account_id: u32, size: AvatarSize) -> Option<(Vec<u8>, Duration)> {
+        let path = self.avatar_path(account_id, size);
+        let bytes = tokio::fs::read(&path).await.ok()?;
+
+        // Modification time is the download moment, access time drives LRU eviction
+        let age = tokio::fs::metadata(&path)
+            .await
+            .and_then(|meta| meta.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+            .unwrap_or_default();
+        touch(&path).await;
+
+        Some((bytes, age))
+    }
+
+    /// Stores image and evicts least recently used entries over the size limit
+    async fn write(&self, account_id: u32, size: AvatarSize, bytes: &[u8]) -> Result<(), AppError> {
+        let _guard = self.write_lock.lock().await;
+
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .map_err(|e| AppError::storage(&self.dir, e))?;
+
+        let path = self.avatar_path(account_id, size);
+        tokio::fs::write(&path, bytes)
+            .await
+            .map_err(|e| AppError::storage(&path, e))?;
+        touch(&path).await;
+
+        let (dir, max_bytes) = (self.dir.clone(), self.max_bytes);
+        tokio::task::spawn_blocking(move || storage::evict_lru(&dir, max_bytes))
+            .await
+            .map_err(|e| AppError::storage(&self.dir, e))?
+    }
+}
+
+/// Marks cache entry as recently used (std only can set file times)
+async fn touch(path: &Path) {
+    let path = path.to_path_buf();
+    let _ = tokio::task::spawn_blocking(move || storage::touch(&path)).await;
+}
+
+/// Parses `avatar/<account_id>/<size>` from protocol URI (both URL forms)
+fn parse_avatar_uri(uri: &Uri) -> Option<(u32, AvatarSize)> {
+    let host = uri.host().unwrap_or("");
+    let mut segments: Vec<&str> = uri
+        .path()
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect();
+
+    if !host.is_empty() && !host.ends_with("localhost") {
+        segments.insert(0, host);
+    }
+
+    match segments.as_slice() {
+        ["avatar", account_id, size] => Some((account_id.parse().ok()?, AvatarSize::parse(size)?)),
+        ["avatar", account_id] => Some((account_id.parse().ok()?, AvatarSize::Full)),
+        _ => None,
+    }
+}
+
+/// Detects image MIME type by signature (Steam serves JPEG by default)
+fn content_type(bytes: &[u8]) -> &'static str {
+    if bytes.starts_with(b"\x89PNG") {
+        "image/png"
+    } else if bytes.starts_with(b"GIF8") {
+        "image/gif"
+    } else if bytes.len() > 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        "image/webp"
+    } else {
+        "image/jpeg"
+    }
+}
+
+/// Downloads avatar from Steam CDN (profile is resolved through the API)
+async fn download_avatar(
+    app: &AppHandle,
+    account_id: u32,
+    size: AvatarSize,
+) -> Result<Vec<u8>, AppError> {
+    let api = app.state::<ApiClient>();
+    let profiles = players::fetch_steam_profiles(&api, &[account_id]).await?;
+    if let Err(e) = app.state::<players::ProfileHistory>().record(&profiles) {
+        api.notices().record(players::HISTORY_NOTICE, e);
+    }
+    let profile = profiles
+        .iter()
+        .find(|profile| profile.account_id == account_id)
+        .ok_or_else(|| AppError::not_found("steam_profile", account_id))?;
+
+    let url = size.url(profile);
+    let response = api
+        .http()
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| AppError::network(url, e))?;
+    let status = response.status();
+
+    if !status.is_success() {
+        return Err(AppError::Http {
+            url: url.to_string(),
+            status: status.as_u16(),
+        });
+    }
+
+    read_limited(url, response, MAX_AVATAR_BYTES).await
+}
+
+/// Reads response body, failing once it grows over `limit` bytes
+async fn read_limited(
+    url: &str,
+    mut response: reqwest::Response,
+    limit: u64,
+) -> Result<Vec<u8>, AppError> {
+    let too_large = |size: u64| {
+        AppError::invalid_response(
+            url,
+            format!("image of {} bytes exceeds the {} byte limit", size, limit),
+        )
+    };
+
+    if let Some(length) = response.content_length() {
+        if length > limit {
+            return Err(too_large(length));
+        }
+    }
+
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| AppError::network(url, e))?
+    {
+        bytes.extend_from_slice(&chunk);
+        if bytes.len() as u64 > limit {
+            return Err(too_large(bytes.len() as u64));
+        }
+    }
+    Ok(bytes)
+}
+
+/// Resolves avatar from cache or network
+async fn load_avatar(
+    app: &AppHandle,
+    account_id: u32,
+    size: AvatarSize,
+) -> Result<Vec<u8>, AppError> {
+    let cache = app.state::<ImageCache>();
+    let cached = cache.read(account_id, size).await;
+
+    if let Some((bytes, age)) = &cached {
+        if *age < REFRESH_AFTER {
+            return Ok(bytes.clone());
+        }
+    }
+
+    match download_avatar(app, account_id, size).await {
+        Ok(bytes) => {
+            let _ = cache.write(account_id, size, &bytes).await;
+            Ok(bytes)
+        }
+        // Offline: fall back to stale copy
+        Err(error) => cached.map(|(bytes, _)| bytes).ok_or(error),
+    }
+}
+
+fn response(status: StatusCode, content_type: &str, body: Vec<u8>) -> Response<Vec<u8>> {
+    Response::builder()
+        .status(status)
+        .header(header::CONTENT_TYPE, content_type)
+        .header(header::ACCESS_CONTROL_ALLOW_ORIGIN, "*")
+        .header(header::CACHE_CONTROL, "max-age=3600")
+        .body(body)
+        .unwrap_or_default()
+}
+
+/// Handles `deadlyze-img://` protocol request
+pub async fn handle_request(app: AppHandle, request: Request<Vec<u8>>) -> Response<Vec<u8>> {
+    let Some((account_id, size)) = parse_avatar_uri(request.uri()) else {
+        return response(
+            StatusCode::BAD_REQUEST,
+            "text/plain",
+            b"Invalid image URL".to_vec(),
+        );
+    };
+
+    match load_avatar(&app, account_id, size).await {
+        Ok(bytes) => {
+            let mime = content_type(&bytes);
+            response(StatusCode::OK, mime, bytes)
+        }
+        Err(error) => response(
+            StatusCode::NOT_FOUND,
+            "text/plain",
+            error.to_string().into_bytes(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uri(value: &str) -> Uri {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_both_url_forms() {
+        assert_eq!(
+            parse_avatar_uri(&uri("deadlyze-img://avatar/42/small")),
+            Some((42, AvatarSize::Small))
+        );
+        assert_eq!(
+            parse_avatar_uri(&uri("http://deadlyze-img.localhost/avatar/42/medium")),
+            Some((42, AvatarSize::Medium))
+        );
+        assert_eq!(
+            parse_avatar_uri(&uri("deadlyze-img://avatar/42")),
+            Some((42, AvatarSize::Full))
+        );
+    }
+
+    #[test]
+    fn builds_parseable_avatar_urls() {
+        let url = avatar_url(42, AvatarSize::Medium);
+        assert!(url.ends_with("avatar/42/medium"), "{}", url);
+        assert_eq!(parse_avatar_uri(&uri(&url)), Some((42, AvatarSize::Medium)));
+    }
+
+    #[test]
+    fn rejects_invalid_avatar_urls() {
+        for value in [
+            "deadlyze-img://avatar/abc/full",
+            "deadlyze-img://avatar/42/huge",
+            "deadlyze-img://hero/42/full",
+            "http://deadlyze-img.localhost/avatar/42/full/extra",
+            "http://deadlyze-img.localhost/",
+        ] {
+            assert_eq!(parse_avatar_uri(&uri(value)), None, "{}", value);
+        }
+    }
+
+    #[test]
+    fn evicts_least_recently_used_first() {
+        let dir = std::env::temp_dir().join(format!("deadlyze-avatars-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let mut cache = ImageCache::new(dir.clone());
+        cache.max_bytes = 250;
+
+        tauri::async_runtime::block_on(async {
+            cache.write(1, AvatarSize::Full, &[0; 100]).await.unwrap();
+            std::thread::sleep(Duration::from_millis(20));
+            cache.write(2, AvatarSize::Full, &[0; 100]).await.unwrap();
+            std::thread::sleep(Duration::from_millis(20));
+            // Reading the first image makes the second one the oldest
+            assert!(cache.read(1, AvatarSize::Full).await.is_some());
+            std::thread::sleep(Duration::from_millis(20));
+            cache.write(3, AvatarSize::Full, &[0; 100]).await.unwrap();
+
+            assert!(cache.read(1, AvatarSize::Full).await.is_some());
+            assert!(cache.read(2, AvatarSize::Full).await.is_none());
+            assert!(cache.read(3, AvatarSize::Full).await.is_some());
+        });
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
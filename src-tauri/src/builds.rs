@@ -0,0 +1,330 @@
+//! Community hero builds (GET /v1/builds) with offline favourites
+//!
+//! Favourite builds are stored in the app data directory as full snapshots, so
+//! they stay available without network. Checking favourites emits
+//! `favorite-build-updated` for each build that got a newer version; builds
+//! that can't be fetched are reported and keep their stored version.
+
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, State};
+
+use crate::api::generated::GetBuildsParams;
+use crate::api::ApiClient;
+use crate::error::AppError;
+use crate::events::Event;
+use crate::storage;
+
+/// Favourites file in the app data directory
+pub const FAVORITES_FILE: &str = "favorite_builds.json";
+
+/// Notice source of favourites load failures
+pub const FAVORITES_NOTICE: &str = "favorite_builds";
+
+const DEFAULT_LIMIT: u32 = 50;
+
+/// Build model comes from the OpenAPI spec
+pub use crate::api::generated::BuildEntry;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BuildSort {
+    /// All-time favourites
+    #[default]
+    Favorites,
+    WeeklyFavorites,
+    UpdatedAt,
+}
+
+impl BuildSort {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Favorites => "favorites",
+            Self::WeeklyFavorites => "weekly_favorites",
+            Self::UpdatedAt => "updated_at",
+        }
+    }
+}
+
+/// Build list filter from the UI
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BuildQuery {
+    #[serde(default)]
+    pub hero_id: Option<u32>,
+    #[serde(default)]
+    pub author_id: Option<u32>,
+    #[serde(default)]
+    pub language: Option<u32>,
+    /// Only builds updated after this Unix timestamp
+    #[serde(default)]
+    pub updated_since: Option<i64>,
+    #[serde(default)]
+    pub sort: BuildSort,
+    #[serde(default)]
+    pub start: Option<u32>,
+    #[serde(default)]
+    pub limit: Option<u32>,
+}
+
+impl BuildQuery {
+    fn to_params(&self) -> GetBuildsParams {
+        GetBuildsParams {
+            hero_id: self.hero_id,
+            author_id: self.author_id,
+            language: self.language,
+            min_last_updated_unix_timestamp: self.updated_since,
+            only_latest: Some(true),
+            sort_by: Some(self.sort.as_str().to_string()),
+            sort_direction: Some("desc".to_string()),
+            start: self.start,
+            limit: Some(self.limit.unwrap_or(DEFAULT_LIMIT)),
+            ..GetBuildsParams::default()
+        }
+    }
+}
+
+/// Favourite build with a newer version available
+#[derive(Debug, Clone, Serialize)]
+pub struct BuildUpdate {
+    pub hero_build_id: u64,
+    pub name: String,
+    pub old_version: u32,
+    pub new_version: u32,
+}
+
+impl Event for BuildUpdate {
+    const NAME: &'static str = "favorite-build-updated";
+}
+
+/// Favourite build that couldn't be checked
+#[derive(Debug, Clone, Serialize)]
+pub struct BuildCheckFailure {
+    pub hero_build_id: u64,
+    pub error: AppError,
+}
+
+/// Result of checking favourites for newer versions
+#[derive(Debug, Clone, Serialize)]
+pub struct FavoriteCheck {
+    pub updates: Vec<BuildUpdate>,
+    pub failures: Vec<BuildCheckFailure>,
+}
+
+/// Favourite builds store (managed as Tauri state)
+pub struct FavoriteBuilds {
+    path: PathBuf,
+    builds: Mutex<Vec<BuildEntry>>,
+}
+
+impl FavoriteBuilds {
+    /// Loads favourites; a corrupt file is moved aside and reported, and the
+    /// returned store starts empty
+    pub fn load(path: PathBuf) -> (Self, Option<AppError>) {
+        let (builds, error) = match storage::load_json_or_set_aside(&path) {
+            Ok(builds) => (builds, None),
+            Err(e) => (Vec::new(), Some(e)),
+        };
+        let favorites = Self {
+            path,
+            builds: Mutex::new(builds),
+        };
+        (favorites, error)
+    }
+
+    fn list(&self) -> Vec<BuildEntry> {
+        self.builds.lock().unwrap().clone()
+    }
+
+    fn upsert(&self, entry: BuildEntry) -> Result<(), AppError> {
+        let mut builds = self.builds.lock().unwrap();
+        let id = entry.hero_build.hero_build_id;
+        match builds.iter_mut().find(|b| b.hero_build.hero_build_id == id) {
+            Some(existing) => *existing = entry,
+            None => builds.push(entry),
+        }
+        storage::save_json(&self.path, &*builds)
+    }
+
+    /// Replaces a stored build (the file is only written when it changed);
+    /// returns `false` when it was removed meanwhile
+    fn update(&self, entry: BuildEntry) -> Result<bool, AppError> {
+        let mut builds = self.builds.lock().unwrap();
+        let id = entry.hero_build.hero_build_id;
+        let Some(existing) = builds.iter_mut().find(|b| b.hero_build.hero_build_id == id) else {
+            return Ok(false);
+        };
+        // Generated models don't implement `PartialEq`
+        if serde_json::to_value(&*existing).ok() == serde_json::to_value(&entry).ok() {
+            return Ok(true);
+        }
+        *existing = entry;
+        storage::save_json(&self.path, &*builds)?;
+        Ok(true)
+    }
+
+    fn remove(&self, hero_build_id: u64) -> Result<(), AppError> {
+        let mut builds = self.builds.lock().unwrap();
+        builds.retain(|b| b.hero_build.hero_build_id != hero_build_id);
+        storage::save_json(&self.path, &*builds)
+    }
+}
+
+/// Fetches builds matching the filter
+pub async fn fetch_builds(
+    api: &ApiClient,
+    query: &BuildQuery,
+) -> Result<Vec<BuildEntry>, AppError> {
+    api.get_builds(&query.to_params()).await
+}
+
+/// Fetches the latest version of a single build
+async fn fetch_latest(api: &ApiClient, hero_build_id: u64) -> Result<Option<BuildEntry>, AppError> {
+    let params = GetBuildsParams {
+        build_id: Some(hero_build_id),
+        only_latest: Some(true),
+        ..GetBuildsParams::default()
+    };
+    let builds = api.get_builds(&params).await?;
+    Ok(builds
+        .into_iter()
+        .max_by_key(|entry| entry.hero_build.version))
+}
+
+/// Returns community builds (most popular first by default)
+#[tauri::command]
+pub async fn list_builds(
+    api: State<'_, ApiClient>,
+    query: Option<BuildQuery>,
+) -> Result<Vec<BuildEntry>, AppError> {
+    fetch_builds(&api, &query.unwrap_or_default()).await
+}
+
+/// Returns locally stored favourite builds (available offline)
+#[tauri::command]
+pub fn get_favorite_builds(favorites: State<'_, FavoriteBuilds>) -> Vec<BuildEntry> {
+    favorites.list()
+}
+
+#[tauri::command]
+pub fn add_favorite_build(
+    favorites: State<'_, FavoriteBuilds>,
+    build: BuildEntry,
+) -> Result<(), AppError> {
+    favorites.upsert(build)
+}
+
+#[tauri::command]
+pub fn remove_favorite_build(
+    favorites: State<'_, FavoriteBuilds>,
+    hero_build_id: u64,
+) -> Result<(), AppError> {
+    favorites.remove(hero_build_id)
+}
+
+/// Refreshes favourites and emits `favorite-build-updated` for each newer version
+///
+/// A build that fails to fetch is reported and doesn't stop the others.
+#[tauri::command]
+pub async fn check_favorite_builds(
+    app: AppHandle,
+    api: State<'_, ApiClient>,
+    favorites: State<'_, FavoriteBuilds>,
+) -> Result<FavoriteCheck, AppError> {
+    let mut check = FavoriteCheck {
+        updates: Vec::new(),
+        failures: Vec::new(),
+    };
+
+    for stored in favorites.list() {
+        let hero_build_id = stored.hero_build.hero_build_id;
+        let latest = match fetch_latest(&api, hero_build_id).await {
+            Ok(Some(latest)) => latest,
+            Ok(None) => continue,
+            Err(error) => {
+                check.failures.push(BuildCheckFailure {
+                    hero_build_id,
+                    error,
+                });
+                continue;
+            }
+        };
+
+        let new_version = latest.hero_build.version;
+        let name = latest.hero_build.name.clone();
+        // Removed while the check was running: don't bring it back
+        if !favorites.update(latest)? {
+            continue;
+        }
+
+        if new_version > stored.hero_build.version {
+            let update = BuildUpdate {
+                hero_build_id,
+                name,
+                old_version: stored.hero_build.version,
+                new_version,
+            };
+            let _ = update.emit(&app);
+            check.updates.push(update);
+        }
+    }
+
+    Ok(check)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(hero_build_id: u64, version: u32) -> BuildEntry {
+        serde_json::from_value(serde_json::json!({
+            "hero_build": {
+                "hero_build_id": hero_build_id,
+                "author_account_id": 1,
+                "hero_id": 1,
+                "language": 0,
+                "version": version,
+                "name": "Build",
+            }
+        }))
+        .unwrap()
+    }
+
+    fn favorites(name: &str) -> (FavoriteBuilds, PathBuf) {
+        let dir = std::env::temp_dir().join(format!("deadlyze-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let (favorites, error) = FavoriteBuilds::load(dir.join(FAVORITES_FILE));
+        assert!(error.is_none());
+        (favorites, dir)
+    }
+
+    #[test]
+    fn update_skips_removed_favorites() {
+        let (favorites, dir) = favorites("builds-removed");
+
+        favorites.upsert(entry(1, 1)).unwrap();
+        favorites.remove(1).unwrap();
+
+        assert!(!favorites.update(entry(1, 2)).unwrap());
+        assert!(favorites.list().is_empty());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn update_saves_only_changed_builds() {
+        let (favorites, dir) = favorites("builds-unchanged");
+        let path = dir.join(FAVORITES_FILE);
+
+        favorites.upsert(entry(1, 1)).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(favorites.update(entry(1, 1)).unwrap());
+        assert!(!path.exists());
+
+        assert!(favorites.update(entry(1, 2)).unwrap());
+        assert!(path.exists());
+        assert_eq!(favorites.list()[0].hero_build.version, 2);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,435 @@
+//! Application error returned by all Tauri commands
+//!
+//! Serialized as `{ code, key, message, context }`: `code` is stable and safe
+//! to branch on, `key` is the i18n key in `en.json` / `ru.json` (`errors.*`),
+//! `context` holds the interpolation values and `message` is an English
+//! fallback for logs.
+
+use std::fmt;
+use std::path::Path;
+
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use serde_json::{json, Value};
+
+#[derive(Debug, Clone)]
+pub enum AppError {
+    WindowNotFound,
+    /// Only raised by the Windows opacity implementation
+    #[cfg(windows)]
+    WindowOpacity {
+        reason: String,
+    },
+    InvalidShortcut {
+        shortcut: String,
+    },
+    ShortcutRegistration {
+        shortcut: String,
+        reason: String,
+    },
+    AppDataDir {
+        reason: String,
+    },
+    OpenFolder {
+        path: String,
+        reason: String,
+    },
+    LaunchGame {
+        reason: String,
+    },
+    /// Only raised by the Windows process check
+    #[cfg(windows)]
+    GameStatus {
+        reason: String,
+    },
+    /// Connection failure (offline, DNS, timeout, proxy)
+    Network {
+        url: String,
+        reason: String,
+    },
+    /// Non-success status of a non-API resource (avatars, RSS feed, assets)
+    Http {
+        url: String,
+        status: u16,
+    },
+    RateLimited {
+        endpoint: String,
+    },
+    ApiNotFound {
+        endpoint: String,
+    },
+    ApiBadRequest {
+        endpoint: String,
+        reason: String,
+    },
+    /// 5xx and other unexpected API statuses
+    ApiUnavailable {
+        endpoint: String,
+        status: u16,
+        reason: String,
+    },
+    /// Response or file content that cannot be decoded
+    InvalidResponse {
+        source: String,
+        reason: String,
+    },
+    Storage {
+        path: String,
+        reason: String,
+    },
+    InvalidSettings {
+        field: String,
+        reason: String,
+    },
+    /// Settings file that is not valid JSON (or has mistyped values)
+    SettingsFile {
+        path: String,
+        line: usize,
+        column: usize,
+        reason: String,
+    },
+    InvalidInput {
+        field: String,
+        reason: String,
+    },
+    NotFound {
+        resource: String,
+        id: String,
+    },
+}
+
+impl AppError {
+    pub fn network(url: impl fmt::Display, reason: impl fmt::Display) -> Self {
+        Self::Network {
+            url: url.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn storage(path: &Path, reason: impl fmt::Display) -> Self {
+        Self::Storage {
+            path: path.display().to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn invalid_response(source: impl fmt::Display, reason: impl fmt::Display) -> Self {
+        Self::InvalidResponse {
+            source: source.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn invalid_settings(field: &str, reason: impl fmt::Display) -> Self {
+        Self::InvalidSettings {
+            field: field.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// JSON error with its position; `field` prefixes the reason for mistyped values
+    pub fn settings_file(path: &Path, field: Option<&str>, error: &serde_json::Error) -> Self {
+        let message = error.to_string();
+        let location = format!(" at line {} column {}", error.line(), error.column());
+        let reason = message.strip_suffix(&location).unwrap_or(&message);
+        Self::SettingsFile {
+            path: path.display().to_string(),
+            line: error.line(),
+            column: error.column(),
+            reason: match field {
+                Some(field) => format!("{}: {}", field, reason),
+                None => reason.to_string(),
+            },
+        }
+    }
+
+    pub fn not_found(resource: &str, id: impl fmt::Display) -> Self {
+        Self::NotFound {
+            resource: resource.to_string(),
+            id: id.to_string(),
+        }
+    }
+
+    /// Maps a non-success API status to the matching error kind
+    pub fn from_api_status(endpoint: &str, status: u16, body: String) -> Self {
+        let endpoint = endpoint.to_string();
+        match status {
+            400 => Self::ApiBadRequest {
+                endpoint,
+                reason: body,
+            },
+            404 => Self::ApiNotFound { endpoint },
+            429 => Self::RateLimited { endpoint },
+            _ => Self::ApiUnavailable {
+                endpoint,
+                status,
+                reason: body,
+            },
+        }
+    }
+
+    /// Stable error code
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::WindowNotFound => "WINDOW_NOT_FOUND",
+            #[cfg(windows)]
+            Self::WindowOpacity { .. } => "WINDOW_OPACITY",
+            Self::InvalidShortcut { .. } => "INVALID_SHORTCUT",
+            Self::ShortcutRegistration { .. } => "SHORTCUT_REGISTRATION",
+            Self::AppDataDir { .. } => "APP_DATA_DIR",
+            Self::OpenFolder { .. } => "OPEN_FOLDER",
+            Self::LaunchGame { .. } => "LAUNCH_GAME",
+            #[cfg(windows)]
+            Self::GameStatus { .. } => "GAME_STATUS",
+            Self::Network { .. } => "NETWORK",
+            Self::Http { .. } => "HTTP",
+            Self::RateLimited { .. } => "RATE_LIMITED",
+            Self::ApiNotFound { .. } => "API_NOT_FOUND",
+            Self::ApiBadRequest { .. } => "API_BAD_REQUEST",
+            Self::ApiUnavailable { .. } => "API_UNAVAILABLE",
+            Self::InvalidResponse { .. } => "INVALID_RESPONSE",
+            Self::Storage { .. } => "STORAGE",
+            Self::InvalidSettings { .. } => "INVALID_SETTINGS",
+            Self::SettingsFile { .. } => "SETTINGS_FILE",
+            Self::InvalidInput { .. } => "INVALID_INPUT",
+            Self::NotFound { .. } => "NOT_FOUND",
+        }
+    }
+
+    /// Locale key of the user-facing message
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            Self::WindowNotFound => "errors.windowNotFound",
+            #[cfg(windows)]
+            Self::WindowOpacity { .. } => "errors.windowOpacity",
+            Self::InvalidShortcut { .. } => "errors.invalidShortcut",
+            Self::ShortcutRegistration { .. } => "errors.shortcutRegistration",
+            Self::AppDataDir { .. } => "errors.appDataDir",
+            Self::OpenFolder { .. } => "errors.openFolder",
+            Self::LaunchGame { .. } => "errors.launchGame",
+            #[cfg(windows)]
+            Self::GameStatus { .. } => "errors.gameStatus",
+            Self::Network { .. } => "errors.network",
+            Self::Http { .. } => "errors.http",
+            Self::RateLimited { .. } => "errors.rateLimited",
+            Self::ApiNotFound { .. } => "errors.apiNotFound",
+            Self::ApiBadRequest { .. } => "errors.apiBadRequest",
+            Self::ApiUnavailable { .. } => "errors.apiUnavailable",
+            Self::InvalidResponse { .. } => "errors.invalidResponse",
+            Self::Storage { .. } => "errors.storage",
+            Self::InvalidSettings { .. } => "errors.invalidSettings",
+            Self::SettingsFile { .. } => "errors.settingsFile",
+            Self::InvalidInput { .. } => "errors.invalidInput",
+            Self::NotFound { .. } => "errors.notFound",
+        }
+    }
+
+    /// Interpolation values for the locale message
+    pub fn context(&self) -> Value {
+        match self {
+            Self::WindowNotFound => json!({}),
+            Self::AppDataDir { reason } | Self::LaunchGame { reason } => {
+                json!({ "reason": reason })
+            }
+            #[cfg(windows)]
+            Self::WindowOpacity { reason } | Self::GameStatus { reason } => {
+                json!({ "reason": reason })
+            }
+            Self::InvalidShortcut { shortcut } => json!({ "shortcut": shortcut }),
+            Self::ShortcutRegistration { shortcut, reason } => {
+                json!({ "shortcut": shortcut, "reason": reason })
+            }
+            Self::OpenFolder { path, reason } | Self::Storage { path, reason } => {
+                json!({ "path": path, "reason": reason })
+            }
+            Self::Network { url, reason } => json!({ "url": url, "reason": reason }),
+            Self::Http { url, status } => json!({ "url": url, "status": status }),
+            Self::RateLimited { endpoint } | Self::ApiNotFound { endpoint } => {
+                json!({ "endpoint": endpoint })
+            }
+            Self::ApiBadRequest { endpoint, reason } => {
+                json!({ "endpoint": endpoint, "reason": reason })
+            }
+            Self::ApiUnavailable {
+                endpoint,
+                status,
+                reason,
+            } => json!({ "endpoint": endpoint, "status": status, "reason": reason }),
+            Self::InvalidResponse { source, reason } => {
+                json!({ "source": source, "reason": reason })
+            }
+            Self::InvalidSettings { field, reason } | Self::InvalidInput { field, reason } => {
+                json!({ "field": field, "reason": reason })
+            }
+            Self::SettingsFile {
+                path,
+                line,
+                column,
+                reason,
+            } => json!({ "path": path, "line": line, "column": column, "reason": reason }),
+            Self::NotFound { resource, id } => json!({ "resource": resource, "id": id }),
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WindowNotFound => write!(f, "Window not found"),
+            #[cfg(windows)]
+            Self::WindowOpacity { reason } => write!(f, "Failed to set window opacity: {}", reason),
+            Self::InvalidShortcut { shortcut } => write!(f, "Invalid shortcut: {}", shortcut),
+            Self::ShortcutRegistration { shortcut, reason } => {
+                write!(f, "Failed to register shortcut {}: {}", shortcut, reason)
+            }
+            Self::AppDataDir { reason } => {
+                write!(f, "Failed to get app data directory: {}", reason)
+            }
+            Self::OpenFolder { path, reason } => {
+                write!(f, "Failed to open folder {}: {}", path, reason)
+            }
+            Self::LaunchGame { reason } => write!(f, "Failed to launch Deadlock: {}", reason),
+            #[cfg(windows)]
+            Self::GameStatus { reason } => {
+                write!(f, "Failed to check Deadlock process: {}", reason)
+            }
+            Self::Network { url, reason } => write!(f, "Request to {} failed: {}", url, reason),
+            Self::Http { url, status } => write!(f, "Request to {} returned {}", url, status),
+            Self::RateLimited { endpoint } => write!(f, "Rate limit exceeded: {}", endpoint),
+            Self::ApiNotFound { endpoint } => write!(f, "Data not found: {}", endpoint),
+            Self::ApiBadRequest { endpoint, reason } => {
+                write!(f, "Invalid parameters for {}: {}", endpoint, reason)
+            }
+            Self::ApiUnavailable {
+                endpoint,
+                status,
+                reason,
+            } => write!(f, "API error {} on {}: {}", status, endpoint, reason),
+            Self::InvalidResponse { source, reason } => {
+                write!(f, "Failed to parse {}: {}", source, reason)
+            }
+            Self::Storage { path, reason } => write!(f, "Storage error at {}: {}", path, reason),
+            Self::InvalidSettings { field, reason } => {
+                write!(f, "Invalid setting {}: {}", field, reason)
+            }
+            Self::SettingsFile {
+                path,
+                line,
+                column,
+                reason,
+            } => write!(
+                f,
+                "Invalid settings file {} at line {} column {}: {}",
+                path, line, column, reason
+            ),
+            Self::InvalidInput { field, reason } => {
+                write!(f, "Invalid input {}: {}", field, reason)
+            }
+            Self::NotFound { resource, id } => write!(f, "{} not found: {}", resource, id),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl Serialize for AppError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("AppError", 4)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("key", self.i18n_key())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.serialize_field("context", &self.context())?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOCALES: &[(&str, &str)] = &[
+        ("en", include_str!("../../src/i18n/locales/en.json")),
+        ("ru", include_str!("../../src/i18n/locales/ru.json")),
+    ];
+
+    /// One error of every kind available on this platform
+    fn samples() -> Vec<AppError> {
+        let reason = || "reason".to_string();
+        #[allow(unused_mut)]
+        let mut samples = vec![
+            AppError::WindowNotFound,
+            AppError::InvalidShortcut {
+                shortcut: "F13".to_string(),
+            },
+            AppError::ShortcutRegistration {
+                shortcut: "F13".to_string(),
+                reason: reason(),
+            },
+            AppError::AppDataDir { reason: reason() },
+            AppError::OpenFolder {
+                path: "dir".to_string(),
+                reason: reason(),
+            },
+            AppError::LaunchGame { reason: reason() },
+            AppError::network("url", "offline"),
+            AppError::Http {
+                url: "url".to_string(),
+                status: 404,
+            },
+            AppError::from_api_status("/v1", 429, String::new()),
+            AppError::from_api_status("/v1", 404, String::new()),
+            AppError::from_api_status("/v1", 400, reason()),
+            AppError::from_api_status("/v1", 503, reason()),
+            AppError::invalid_response("/v1", "eof"),
+            AppError::storage(Path::new("file"), "denied"),
+            AppError::invalid_settings("opacity", "out of range"),
+            AppError::settings_file(
+                Path::new("settings.json"),
+                None,
+                &serde_json::from_str::<Value>("{").unwrap_err(),
+            ),
+            AppError::InvalidInput {
+                field: "page".to_string(),
+                reason: reason(),
+            },
+            AppError::not_found("build", 1),
+        ];
+        #[cfg(windows)]
+        samples.extend([
+            AppError::WindowOpacity { reason: reason() },
+            AppError::GameStatus { reason: reason() },
+        ]);
+        samples
+    }
+
+    #[test]
+    fn serializes_code_key_message_and_context() {
+        let error = AppError::from_api_status("/v1/leaderboard", 503, "down".to_string());
+
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            json!({
+                "code": "API_UNAVAILABLE",
+                "key": "errors.apiUnavailable",
+                "message": "API error 503 on /v1/leaderboard: down",
+                "context": { "endpoint": "/v1/leaderboard", "status": 503, "reason": "down" },
+            })
+        );
+    }
+
+    #[test]
+    fn every_key_has_a_translation() {
+        for (locale, source) in LOCALES {
+            let messages: Value = serde_json::from_str(source).unwrap();
+            for error in samples() {
+                let pointer = format!("/{}", error.i18n_key().replace('.', "/"));
+                assert!(
+                    messages.pointer(&pointer).is_some_and(Value::is_string),
+                    "{} missing in {}.json",
+                    error.i18n_key(),
+                    locale
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! Events emitted to the frontend
+//!
+//! Every payload type implements [`Event`]; the build script exports the
+//! name-to-payload map into the TypeScript bindings.
+
+use serde::Serialize;
+use tauri::{Emitter, Runtime};
+
+/// Event payload with its frontend event name
+pub trait Event: Serialize + Clone {
+    const NAME: &'static str;
+
+    fn emit<R: Runtime>(&self, emitter: &impl Emitter<R>) -> tauri::Result<()> {
+        emitter.emit(Self::NAME, self)
+    }
+}
@@ -0,0 +1,363 @@
+//! Leaderboards per region and per hero (GET /v1/leaderboard/{region}[/{hero_id}])
+//!
+//! Entries are joined with Steam profiles; profile history serves as a fallback
+//! cache when the profile endpoint can't be reached.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::api::generated::LeaderboardEntry;
+use crate::api::ApiClient;
+use crate::assets::{Assets, ResolvedAsset};
+use crate::avatars::{self, AvatarSize};
+use crate::error::AppError;
+use crate::players::{self, ProfileHistory};
+use crate::settings::SettingsStore;
+
+/// Leaderboards are refreshed by the API every few minutes
+const CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+
+const DEFAULT_PAGE_SIZE: usize = 50;
+const MAX_PAGE_SIZE: usize = 200;
+
+/// Accounts per Steam profiles request (entries may list several candidates)
+const PROFILE_BATCH: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Region {
+    Europe,
+    Asia,
+    NAmerica,
+    SAmerica,
+    Oceania,
+}
+
+impl Region {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Europe => "Europe",
+            Self::Asia => "Asia",
+            Self::NAmerica => "NAmerica",
+            Self::SAmerica => "SAmerica",
+            Self::Oceania => "Oceania",
+        }
+    }
+}
+
+/// Leaderboard row as sent to the UI
+#[derive(Debug, Clone, Serialize)]
+pub struct LeaderboardRow {
+    pub rank: Option<u32>,
+    pub account_name: Option<String>,
+    pub account_id: Option<u32>,
+    pub personaname: Option<String>,
+    pub avatar: Option<String>,
+    pub badge_level: Option<u32>,
+    pub top_hero_ids: Vec<u32>,
+    /// Names and icons of the top heroes in the UI language (unknown ones left out)
+    pub top_heroes: Vec<ResolvedAsset>,
+    /// Row belongs to the current user
+    pub is_own: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LeaderboardPage {
+    pub region: Region,
+    pub hero_id: Option<u32>,
+    pub page: usize,
+    pub page_size: usize,
+    pub total_entries: usize,
+    pub rows: Vec<LeaderboardRow>,
+    /// User's own row (also when it is outside the requested page)
+    pub own_row: Option<LeaderboardRow>,
+}
+
+/// Leaderboard request from the UI
+#[derive(Debug, Clone, Deserialize)]
+pub struct LeaderboardQuery {
+    pub region: Region,
+    #[serde(default)]
+    pub hero_id: Option<u32>,
+    /// Zero-based page number
+    #[serde(default)]
+    pub page: usize,
+    /// Rows per page (clamped to 1..=200)
+    #[serde(default)]
+    pub page_size: Option<usize>,
+    /// Current user's account (row gets highlighted)
+    #[serde(default)]
+    pub account_id: Option<u32>,
+}
+
+type CacheKey = (Region, Option<u32>);
+
+/// In-memory leaderboard cache (managed as Tauri state)
+#[derive(Default)]
+pub struct LeaderboardCache {
+    entries: Mutex<HashMap<CacheKey, (Instant, Vec<LeaderboardEntry>)>>,
+}
+
+/// Fetches full leaderboard of the region (optionally for a single hero)
+pub async fn fetch_leaderboard(
+    api: &ApiClient,
+    region: Region,
+    hero_id: Option<u32>,
+) -> Result<Vec<LeaderboardEntry>, AppError> {
+    let leaderboard = match hero_id {
+        Some(hero_id) => api.get_hero_leaderboard(region.as_str(), hero_id).await?,
+        None => api.get_leaderboard(region.as_str()).await?,
+    };
+    Ok(leaderboard.entries)
+}
+
+async fn cached_leaderboard(
+    api: &ApiClient,
+    cache: &LeaderboardCache,
+    region: Region,
+    hero_id: Option<u32>,
+) -> Result<Vec<LeaderboardEntry>, AppError> {
+    let key = (region, hero_id);
+    if let Some((fetched_at, entries)) = cache.entries.lock().unwrap().get(&key) {
+        if fetched_at.elapsed() < CACHE_TTL {
+            return Ok(entries.clone());
+        }
+    }
+
+    let entries = fetch_leaderboard(api, region, hero_id).await?;
+    cache
+        .entries
+        .lock()
+        .unwrap()
+        .insert(key, (Instant::now(), entries.clone()));
+    Ok(entries)
+}
+
+/// Picks the account matching entry name among possible accounts
+fn resolve_account(entry: &LeaderboardEntry, names: &HashMap<u32, String>) -> Option<u32> {
+    entry
+        .possible_account_ids
+        .iter()
+        .find(|id| names.get(id) == entry.account_name.as_ref())
+        .or_else(|| entry.possible_account_ids.first())
+        .copied()
+}
+
+/// Rows per page (clamped) and index of the first entry of the page
+fn page_bounds(page: usize, page_size: Option<usize>) -> (usize, usize) {
+    let page_size = page_size
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    (page_size, page.saturating_mul(page_size))
+}
+
+/// Persona names of the possible accounts of the entries
+async fn fetch_names(
+    api: &ApiClient,
+    history: &ProfileHistory,
+    entries: &[LeaderboardEntry],
+) -> HashMap<u32, String> {
+    let mut account_ids: Vec<u32> = entries
+        .iter()
+        .flat_map(|entry| entry.possible_account_ids.iter().copied())
+        .collect();
+    account_ids.sort_unstable();
+    account_ids.dedup();
+
+    let mut names: HashMap<u32, String> = HashMap::new();
+    for batch in account_ids.chunks(PROFILE_BATCH) {
+        let Ok(profiles) = players::fetch_steam_profiles(api, batch).await else {
+            continue;
+        };
+        if let Err(e) = history.record(&profiles) {
+            api.notices().record(players::HISTORY_NOTICE, e);
+        }
+        for profile in profiles {
+            names.insert(profile.account_id, profile.personaname);
+        }
+    }
+    // Offline or partial response: use last known profiles
+    for account_id in &account_ids {
+        if !names.contains_key(account_id) {
+            if let Some(snapshot) = history.latest(*account_id) {
+                names.insert(*account_id, snapshot.personaname);
+            }
+        }
+    }
+    names
+}
+
+/// Joins the entry with the Steam profile (avatar served by the image protocol)
+/// and hero names
+fn build_row(
+    entry: &LeaderboardEntry,
+    names: &HashMap<u32, String>,
+    assets: &Assets,
+    language: &str,
+    own_account_id: Option<u32>,
+) -> LeaderboardRow {
+    let account_id = resolve_account(entry, names);
+    let personaname = account_id.and_then(|id| names.get(&id)).cloned();
+    LeaderboardRow {
+        rank: entry.rank,
+        account_name: entry.account_name.clone(),
+        account_id,
+        avatar: account_id
+            .filter(|_| personaname.is_some())
+            .map(|id| avatars::avatar_url(id, AvatarSize::Full)),
+        personaname,
+        badge_level: entry.badge_level,
+        top_hero_ids: entry.top_hero_ids.clone(),
+        top_heroes: entry
+            .top_hero_ids
+            .iter()
+            .filter_map(|&id| assets.hero(id, language))
+            .collect(),
+        is_own: own_account_id.is_some() && account_id == own_account_id,
+    }
+}
+
+/// User's row among the entries (the entry resolved to the account)
+fn own_row(
+    entries: &[LeaderboardEntry],
+    names: &HashMap<u32, String>,
+    assets: &Assets,
+    language: &str,
+    own_account_id: u32,
+) -> Option<LeaderboardRow> {
+    entries
+        .iter()
+        .find(|entry| resolve_account(entry, names) == Some(own_account_id))
+        .map(|entry| build_row(entry, names, assets, language, Some(own_account_id)))
+}
+
+/// Returns a leaderboard page joined with Steam profiles
+#[tauri::command]
+pub async fn get_leaderboard(
+    api: State<'_, ApiClient>,
+    cache: State<'_, LeaderboardCache>,
+    history: State<'_, ProfileHistory>,
+    assets: State<'_, Assets>,
+    settings: State<'_, SettingsStore>,
+    query: LeaderboardQuery,
+) -> Result<LeaderboardPage, AppError> {
+    let LeaderboardQuery {
+        region,
+        hero_id,
+        page,
+        page_size,
+        account_id,
+    } = query;
+    let entries = cached_leaderboard(&api, &cache, region, hero_id).await?;
+
+    let (page_size, offset) = page_bounds(page, page_size);
+    let page_entries = &entries[offset.min(entries.len())..];
+    let page_entries = &page_entries[..page_size.min(page_entries.len())];
+
+    let language = settings.get().language;
+    let names = fetch_names(&api, &history, page_entries).await;
+    let rows: Vec<LeaderboardRow> = page_entries
+        .iter()
+        .map(|entry| build_row(entry, &names, &assets, &language, account_id))
+        .collect();
+
+    let own_row = match (rows.iter().find(|row| row.is_own), account_id) {
+        (Some(row), _) => Some(row.clone()),
+        (None, Some(own)) => {
+            // Off-page candidates listing the account
+            let candidates: Vec<LeaderboardEntry> = entries
+                .iter()
+                .filter(|entry| entry.possible_account_ids.contains(&own))
+                .cloned()
+                .collect();
+            let names = fetch_names(&api, &history, &candidates).await;
+            own_row(&candidates, &names, &assets, &language, own)
+        }
+        (None, None) => None,
+    };
+
+    Ok(LeaderboardPage {
+        region,
+        hero_id,
+        page,
+        page_size,
+        total_entries: entries.len(),
+        rows,
+        own_row,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(rank: u32, account_name: &str, possible_account_ids: &[u32]) -> LeaderboardEntry {
+        LeaderboardEntry {
+            rank: Some(rank),
+            account_name: Some(account_name.to_string()),
+            possible_account_ids: possible_account_ids.to_vec(),
+            ..LeaderboardEntry::default()
+        }
+    }
+
+    fn names(names: &[(u32, &str)]) -> HashMap<u32, String> {
+        names
+            .iter()
+            .map(|&(id, name)| (id, name.to_string()))
+            .collect()
+    }
+
+    fn assets() -> Assets {
+        Assets::load(std::env::temp_dir().join("deadlyze-missing-assets.json"))
+    }
+
+    #[test]
+    fn clamps_page_size_and_offset() {
+        assert_eq!(page_bounds(0, None), (DEFAULT_PAGE_SIZE, 0));
+        assert_eq!(page_bounds(2, Some(0)), (1, 2));
+        assert_eq!(page_bounds(1, Some(1000)), (MAX_PAGE_SIZE, MAX_PAGE_SIZE));
+        assert_eq!(page_bounds(usize::MAX, Some(10)), (10, usize::MAX));
+    }
+
+    #[test]
+    fn resolves_account_by_name() {
+        let names = names(&[(1, "Someone"), (2, "Player")]);
+
+        assert_eq!(
+            resolve_account(&entry(1, "Player", &[1, 2]), &names),
+            Some(2)
+        );
+        // No profile with the board name: first candidate
+        assert_eq!(
+            resolve_account(&entry(1, "Other", &[3, 1]), &names),
+            Some(3)
+        );
+        assert_eq!(resolve_account(&entry(1, "Player", &[]), &names), None);
+    }
+
+    #[test]
+    fn marks_only_the_resolved_account_as_own() {
+        let names = names(&[(1, "Player"), (2, "Someone")]);
+        let entries = [entry(1, "Player", &[1, 2]), entry(2, "Someone", &[1, 2])];
+        let (assets, language) = (assets(), "en");
+
+        let rows: Vec<LeaderboardRow> = entries
+            .iter()
+            .map(|entry| build_row(entry, &names, &assets, language, Some(2)))
+            .collect();
+        assert!(!rows[0].is_own);
+        assert!(rows[1].is_own);
+        assert_eq!(
+            rows[1].avatar.as_deref(),
+            Some(avatars::avatar_url(2, AvatarSize::Full).as_str())
+        );
+
+        let own = own_row(&entries, &names, &assets, language, 2).unwrap();
+        assert_eq!((own.rank, own.account_id), (Some(2), Some(2)));
+        assert!(own.is_own);
+        assert!(own_row(&entries, &names, &assets, language, 3).is_none());
+    }
+}
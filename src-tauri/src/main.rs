@@ -1,8 +1,24 @@
 // Prevents additional console window on Windows in release mode
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod analytics;
+mod api;
+mod assets;
+mod avatars;
+mod builds;
+mod error;
+mod events;
+mod leaderboard;
+mod matches;
+mod patches;
+mod players;
+mod settings;
+mod storage;
+
 use std::sync::Mutex;
-use tauri::{Emitter, Manager};
+use tauri::Manager;
+
+use error::AppError;
 
 #[cfg(target_os = "windows")]
 use std::os::windows::process::CommandExt;
@@ -21,7 +37,7 @@ struct AppState {
 
 /// Sets window opacity (20-100)
 #[tauri::command]
-fn set_window_opacity(app: tauri::AppHandle, opacity: f64) -> Result<(), String> {
+fn set_window_opacity(app: tauri::AppHandle, opacity: f64) -> Result<(), AppError> {
     if let Some(window) = app.get_webview_window("main") {
         #[cfg(target_os = "windows")]
         {
@@ -31,7 +47,9 @@ fn set_window_opacity(app: tauri::AppHandle, opacity: f64) -> Result<(), String>
                 GWL_EXSTYLE, LWA_ALPHA, WS_EX_LAYERED,
             };
 
-            let hwnd = window.hwnd().map_err(|e| e.to_string())?;
+            let hwnd = window.hwnd().map_err(|e| AppError::WindowOpacity {
+                reason: e.to_string(),
+            })?;
             let hwnd = HWND(hwnd.0);
 
             unsafe {
@@ -40,30 +58,36 @@ fn set_window_opacity(app: tauri::AppHandle, opacity: f64) -> Result<(), String>
                 
                 let alpha = ((opacity / 100.0) * 255.0) as u8;
                 SetLayeredWindowAttributes(hwnd, COLORREF(0), alpha, LWA_ALPHA)
-                    .ok()
-                    .ok_or_else(|| "Failed to set window opacity".to_string())?;
+                    .map_err(|e| AppError::WindowOpacity {
+                        reason: e.to_string(),
+                    })?;
             }
         }
         Ok(())
     } else {
-        Err("Window not found".to_string())
+        Err(AppError::WindowNotFound)
     }
 }
 
 /// Registers global shortcut (unregisters previous)
 #[tauri::command]
-fn register_shortcut(app: tauri::AppHandle, shortcut: String) -> Result<(), String> {
+fn register_shortcut(app: tauri::AppHandle, shortcut: String) -> Result<(), AppError> {
     use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
     
     let state = app.state::<AppState>();
     
     let _ = app.global_shortcut().unregister_all();
     
-    let parsed_shortcut: Shortcut = shortcut.parse().map_err(|e| format!("Invalid shortcut: {:?}", e))?;
+    let parsed_shortcut: Shortcut = shortcut.parse().map_err(|_| AppError::InvalidShortcut {
+        shortcut: shortcut.clone(),
+    })?;
     
     app.global_shortcut()
         .register(parsed_shortcut)
-        .map_err(|e| e.to_string())?;
+        .map_err(|e| AppError::ShortcutRegistration {
+            shortcut: shortcut.clone(),
+            reason: e.to_string(),
+        })?;
     
     *state.current_shortcut.lock().unwrap() = Some(shortcut);
     
@@ -72,7 +96,7 @@ fn register_shortcut(app: tauri::AppHandle, shortcut: String) -> Result<(), Stri
 
 /// Temporarily disables shortcut handling (used during editing)
 #[tauri::command]
-fn disable_shortcut(app: tauri::AppHandle) -> Result<(), String> {
+fn disable_shortcut(app: tauri::AppHandle) -> Result<(), AppError> {
     let state = app.state::<AppState>();
     *state.shortcut_enabled.lock().unwrap() = false;
     Ok(())
@@ -80,7 +104,7 @@ fn disable_shortcut(app: tauri::AppHandle) -> Result<(), String> {
 
 /// Re-enables shortcut handling
 #[tauri::command]
-fn enable_shortcut(app: tauri::AppHandle) -> Result<(), String> {
+fn enable_shortcut(app: tauri::AppHandle) -> Result<(), AppError> {
     let state = app.state::<AppState>();
     *state.shortcut_enabled.lock().unwrap() = true;
     Ok(())
@@ -88,51 +112,88 @@ fn enable_shortcut(app: tauri::AppHandle) -> Result<(), String> {
 
 /// Opens the application data folder in file explorer
 #[tauri::command]
-fn open_app_folder(app: tauri::AppHandle) -> Result<(), String> {
+fn open_app_folder(app: tauri::AppHandle) -> Result<(), AppError> {
     let app_data_dir = app
         .path()
         .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+        .map_err(|e| AppError::AppDataDir {
+            reason: e.to_string(),
+        })?;
 
     #[cfg(target_os = "windows")]
     {
         std::process::Command::new("explorer")
-            .arg(app_data_dir)
+            .arg(&app_data_dir)
             .spawn()
-            .map_err(|e| format!("Failed to open folder: {}", e))?;
+            .map_err(|e| AppError::OpenFolder {
+                path: app_data_dir.display().to_string(),
+                reason: e.to_string(),
+            })?;
     }
 
     #[cfg(target_os = "macos")]
     {
         std::process::Command::new("open")
-            .arg(app_data_dir)
+            .arg(&app_data_dir)
             .spawn()
-            .map_err(|e| format!("Failed to open folder: {}", e))?;
+            .map_err(|e| AppError::OpenFolder {
+                path: app_data_dir.display().to_string(),
+                reason: e.to_string(),
+            })?;
     }
 
     #[cfg(target_os = "linux")]
     {
         std::process::Command::new("xdg-open")
-            .arg(app_data_dir)
+            .arg(&app_data_dir)
             .spawn()
-            .map_err(|e| format!("Failed to open folder: {}", e))?;
+            .map_err(|e| AppError::OpenFolder {
+                path: app_data_dir.display().to_string(),
+                reason: e.to_string(),
+            })?;
     }
 
     Ok(())
 }
 
-/// Launches Deadlock game via Steam (App ID: 1422450)
+/// Launches Deadlock game via Steam (App ID: 1422450) with the launch options from settings
 #[tauri::command]
-fn launch_deadlock() -> Result<(), String> {
+fn launch_deadlock(store: tauri::State<'_, settings::SettingsStore>) -> Result<(), AppError> {
+    // Launch options are validated to be safe on the command line
+    let url = settings::steam_launch_url(&store.get().launch_options);
+
     #[cfg(target_os = "windows")]
     {
         const CREATE_NO_WINDOW: u32 = 0x08000000;
         
+        // The empty argument is the window title of `start`
         std::process::Command::new("cmd")
-            .args(["/C", "start", "steam://rungameid/1422450"])
+            .args(["/C", "start", "", &url])
             .creation_flags(CREATE_NO_WINDOW)
             .spawn()
-            .map_err(|e| e.to_string())?;
+            .map_err(|e| AppError::LaunchGame {
+                reason: e.to_string(),
+            })?;
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("open")
+            .arg(&url)
+            .spawn()
+            .map_err(|e| AppError::LaunchGame {
+                reason: e.to_string(),
+            })?;
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        std::process::Command::new("xdg-open")
+            .arg(&url)
+            .spawn()
+            .map_err(|e| AppError::LaunchGame {
+                reason: e.to_string(),
+            })?;
     }
     
     Ok(())
@@ -140,7 +201,7 @@ fn launch_deadlock() -> Result<(), String> {
 
 /// Checks if Deadlock game is currently running
 #[tauri::command]
-fn is_deadlock_running() -> Result<bool, String> {
+fn is_deadlock_running() -> Result<bool, AppError> {
     #[cfg(target_os = "windows")]
     {
         const CREATE_NO_WINDOW: u32 = 0x08000000;
@@ -154,7 +215,9 @@ fn is_deadlock_running() -> Result<bool, String> {
             ])
             .creation_flags(CREATE_NO_WINDOW)
             .output()
-            .map_err(|e| e.to_string())?;
+            .map_err(|e| AppError::GameStatus {
+                reason: e.to_string(),
+            })?;
 
         let stdout = String::from_utf8_lossy(&output.stdout);
         Ok(!stdout.trim().is_empty())
@@ -217,7 +280,8 @@ fn main() {
                         }
                         
                         // Restore opacity without focus (overlay behavior)
-                        let _ = window.emit("load-saved-opacity", ());
+                        let opacity = app.state::<settings::SettingsStore>().get().opacity;
+                        let _ = set_window_opacity(app.clone(), f64::from(opacity));
                         let _ = window.set_ignore_cursor_events(false);
                         *is_hidden = false;
                     } else {
@@ -229,6 +293,12 @@ fn main() {
                 }
             })
             .build())
+        .register_asynchronous_uri_scheme_protocol(avatars::PROTOCOL, |ctx, request, responder| {
+            let app = ctx.app_handle().clone();
+            tauri::async_runtime::spawn(async move {
+                responder.respond(avatars::handle_request(app, request).await);
+            });
+        })
         .setup(|app| {
             app.manage(AppState {
                 is_hidden: Mutex::new(false),
@@ -236,6 +306,63 @@ fn main() {
                 current_shortcut: Mutex::new(None),
                 shortcut_enabled: Mutex::new(true),
             });
+            let app_data_dir = app.path().app_data_dir()?;
+            let settings_store =
+                settings::SettingsStore::load(app_data_dir.join(settings::SETTINGS_FILE));
+            let settings = settings_store.get();
+            app.manage(settings_store);
+            app.manage(api::ApiClient::new(&settings.api));
+            app.manage(leaderboard::LeaderboardCache::default());
+            app.manage(players::PlayerSearch::default());
+
+            let (history, history_error) =
+                players::ProfileHistory::load(app_data_dir.join(players::HISTORY_FILE));
+            if let Some(e) = history_error {
+                app.state::<api::ApiClient>().notices().record(players::HISTORY_NOTICE, e);
+            }
+            app.manage(history);
+            app.manage(players::MatchHistoryStore::new(
+                app_data_dir.join(players::MATCH_HISTORY_DIR),
+            ));
+            app.manage(assets::Assets::load(app_data_dir.join(assets::ASSETS_FILE)));
+            assets::refresh_if_incomplete(app.handle());
+            let (profiles, profiles_error) = settings::profiles::Profiles::load(
+                app_data_dir.join(settings::profiles::PROFILES_FILE),
+                app_data_dir.join(settings::profiles::EXPORT_DIR),
+            );
+            if let Some(e) = profiles_error {
+                app.state::<api::ApiClient>().notices().record(settings::profiles::PROFILES_NOTICE, e);
+            }
+            app.manage(profiles);
+            let (favorites, favorites_error) =
+                builds::FavoriteBuilds::load(app_data_dir.join(builds::FAVORITES_FILE));
+            if let Some(e) = favorites_error {
+                app.state::<api::ApiClient>().notices().record(builds::FAVORITES_NOTICE, e);
+            }
+            app.manage(favorites);
+
+            let cache_dir = app.path().app_cache_dir()?;
+            app.manage(avatars::ImageCache::new(cache_dir.join("avatars")));
+            app.manage(matches::MatchCache::new(
+                cache_dir.join(matches::MATCH_CACHE_DIR),
+            ));
+
+            // Apply stored window settings (the frontend only renders them)
+            if let Err(e) = register_shortcut(app.handle().clone(), settings.shortcut.clone()) {
+                eprintln!("{}", e);
+            }
+            if let Err(e) = set_window_opacity(app.handle().clone(), f64::from(settings.opacity)) {
+                eprintln!("{}", e);
+            }
+            match settings::watcher::watch(
+                app.handle(),
+                &app_data_dir.join(settings::SETTINGS_FILE),
+            ) {
+                Ok(watcher) => {
+                    app.manage(watcher);
+                }
+                Err(e) => eprintln!("Settings hot reload disabled: {}", e),
+            }
             
             // Handle window focus event (clicking on taskbar icon shows WITH focus)
             if let Some(window) = app.get_webview_window("main") {
@@ -252,7 +379,9 @@ fn main() {
                             // When window gains focus via taskbar click, restore if hidden
                             if is_hidden {
                                 if let Some(win) = app_handle.get_webview_window("main") {
-                                    let _ = win.emit("load-saved-opacity", ());
+                                    let opacity =
+                                        app_handle.state::<settings::SettingsStore>().get().opacity;
+                                    let _ = set_window_opacity(app_handle.clone(), f64::from(opacity));
                                     let _ = win.set_ignore_cursor_events(false);
                                     let _ = win.set_focus();
                                     
@@ -279,8 +408,53 @@ fn main() {
             enable_shortcut,
             open_app_folder,
             launch_deadlock,
-            is_deadlock_running
+            is_deadlock_running,
+            settings::get_settings,
+            settings::get_settings_error,
+            settings::update_settings,
+            settings::reset_settings,
+            settings::profiles::list_profiles,
+            settings::profiles::save_profile,
+            settings::profiles::switch_profile,
+            settings::profiles::delete_profile,
+            settings::profiles::export_profile,
+            settings::profiles::list_profile_files,
+            settings::profiles::import_profile,
+            patches::get_patch_notes,
+            patches::get_patch_timeline,
+            patches::get_patch_period,
+            players::get_steam_profiles,
+            players::get_profile_history,
+            players::sync_match_history,
+            players::get_match_history,
+            players::search_players,
+            players::get_mates,
+            matches::get_match_details,
+            analytics::get_badge_distribution,
+            analytics::heroes::get_hero_tier_list,
+            analytics::items::get_item_stats,
+            analytics::items::get_top_items,
+            analytics::counters::get_counter_picks,
+            leaderboard::get_leaderboard,
+            assets::get_assets,
+            assets::resolve_assets,
+            assets::refresh_assets,
+            builds::list_builds,
+            builds::get_favorite_builds,
+            builds::add_favorite_build,
+            builds::remove_favorite_build,
+            builds::check_favorite_builds,
+            api::drift::get_schema_drift,
+            api::cache::get_api_diagnostics
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while running tauri application")
+        .run(|app, event| {
+            // Profile history is written in the background; don't lose the last changes
+            if let tauri::RunEvent::Exit = event {
+                if let Err(e) = app.state::<players::ProfileHistory>().flush() {
+                    eprintln!("{}", e);
+                }
+            }
+        });
 }
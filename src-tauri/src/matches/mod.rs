@@ -0,0 +1,360 @@
+//! Match metadata (GET /v1/matches/{match_id}/metadata)
+//!
+//! Finished matches never change, so metadata is cached on disk after the
+//! first download and the post-game screen opens without network access.
+//! The cache is trimmed least-recently-used first over [`MAX_CACHE_BYTES`].
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use serde::Serialize;
+use tauri::State;
+
+use crate::api::ApiClient;
+use crate::assets::{Assets, ResolvedAssets};
+use crate::error::AppError;
+use crate::settings::SettingsStore;
+use crate::storage;
+
+/// Cache directory for match metadata (inside app cache directory)
+pub const MATCH_CACHE_DIR: &str = "matches";
+
+/// Cache size limit (bytes)
+const MAX_CACHE_BYTES: u64 = 256 * 1024 * 1024;
+
+/// Downloads between size checks (a check scans the whole directory)
+const EVICT_EVERY: u32 = 16;
+
+/// Match metadata models come from the OpenAPI spec
+pub use crate::api::generated::{MatchInfo, MatchMetadata, MatchPlayer, Objective};
+
+/// Team summary for the post-game screen
+#[derive(Debug, Clone, Serialize)]
+pub struct TeamDetails {
+    pub team: u32,
+    pub won: bool,
+    pub kills: u32,
+    pub net_worth: u32,
+    /// Sorted by net worth (highest first)
+    pub players: Vec<MatchPlayer>,
+    /// Own objectives destroyed by the enemy, in destruction order
+    pub objectives_lost: Vec<Objective>,
+}
+
+/// Match details as sent to the UI
+#[derive(Debug, Clone, Serialize)]
+pub struct MatchDetails {
+    pub match_id: u64,
+    /// Names and icons of the heroes and items in the match (UI language)
+    pub assets: ResolvedAssets,
+    pub start_time: i64,
+    pub duration_s: u32,
+    pub winning_team: u32,
+    pub game_mode: i32,
+    pub match_mode: i32,
+    pub teams: Vec<TeamDetails>,
+}
+
+impl From<MatchInfo> for MatchDetails {
+    fn from(info: MatchInfo) -> Self {
+        let mut team_ids: Vec<u32> = info.players.iter().map(|player| player.team).collect();
+        team_ids.sort_unstable();
+        team_ids.dedup();
+
+        let teams = team_ids
+            .into_iter()
+            .map(|team| {
+                let mut players: Vec<MatchPlayer> = info
+                    .players
+                    .iter()
+                    .filter(|player| player.team == team)
+                    .cloned()
+                    .map(|mut player| {
+                        player.items.sort_by_key(|item| item.game_time_s);
+                        player.stats.sort_by_key(|snapshot| snapshot.time_stamp_s);
+                        player
+                    })
+                    .collect();
+                players.sort_by_key(|player| std::cmp::Reverse(player.net_worth));
+
+                let mut objectives_lost: Vec<Objective> = info
+                    .objectives
+                    .iter()
+                    .filter(|objective| objective.team == team && objective.destroyed_time_s > 0)
+                    .cloned()
+                    .collect();
+                objectives_lost.sort_by_key(|objective| objective.destroyed_time_s);
+
+                TeamDetails {
+                    team,
+                    won: team == info.winning_team,
+                    kills: players.iter().map(|player| player.kills).sum(),
+                    net_worth: players.iter().map(|player| player.net_worth).sum(),
+                    players,
+                    objectives_lost,
+                }
+            })
+            .collect();
+
+        Self {
+            match_id: info.match_id,
+            assets: ResolvedAssets::default(),
+            start_time: info.start_time,
+            duration_s: info.duration_s,
+            winning_team: info.winning_team,
+            game_mode: info.game_mode,
+            match_mode: info.match_mode,
+            teams,
+        }
+    }
+}
+
+/// On-disk match metadata cache (managed as Tauri state)
+pub struct MatchCache {
+    dir: PathBuf,
+    max_bytes: u64,
+    /// Downloads stored since the last size check
+    puts: AtomicU32,
+}
+
+impl MatchCache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            max_bytes: MAX_CACHE_BYTES,
+            // First download after start checks the size
+            puts: AtomicU32::new(EVICT_EVERY - 1),
+        }
+    }
+
+    fn path(&self, match_id: u64) -> PathBuf {
+        self.dir.join(format!("{}.json", match_id))
+    }
+
+    /// Cached metadata (no download); marks it as recently used
+    pub async fn get(&self, match_id: u64) -> Option<MatchMetadata> {
+        let path = self.path(match_id);
+        tauri::async_runtime::spawn_blocking(move || {
+            let metadata = storage::load_json::<Option<MatchMetadata>>(&path)
+                .ok()
+                .flatten()?;
+            storage::touch(&path);
+            Some(metadata)
+        })
+        .await
+        .ok()
+        .flatten()
+    }
+
+    /// Stores metadata and evicts least recently used entries over the size limit
+    async fn put(&self, metadata: &MatchMetadata) -> Result<(), AppError> {
+        let path = self.path(metadata.match_info.match_id);
+        let metadata = metadata.clone();
+        let evict = self.puts.fetch_add(1, Ordering::Relaxed) % EVICT_EVERY == EVICT_EVERY - 1;
+        let (dir, max_bytes) = (self.dir.clone(), self.max_bytes);
+
+        tauri::async_runtime::spawn_blocking(move || {
+            storage::save_json(&path, &metadata)?;
+            storage::touch(&path);
+            if evict {
+                storage::evict_lru(&dir, max_bytes)?;
+            }
+            Ok(())
+        })
+        .await
+        .map_err(|e| AppError::storage(&self.dir, e))?
+    }
+}
+
+/// Fetches match metadata from the API
+pub async fn fetch_match_metadata(
+    api: &ApiClient,
+    match_id: u64,
+) -> Result<MatchMetadata, AppError> {
+    api.get_match_metadata(match_id).await
+}
+
+/// Returns match metadata from cache, downloading it on first request
+pub async fn load_match_metadata(
+    api: &ApiClient,
+    cache: &MatchCache,
+    match_id: u64,
+) -> Result<MatchMetadata, AppError> {
+    if let Some(metadata) = cache.get(match_id).await {
+        return Ok(metadata);
+    }
+
+    let metadata = fetch_match_metadata(api, match_id).await?;
+    // Cache failure only costs a re-download next time
+    let _ = cache.put(&metadata).await;
+    Ok(metadata)
+}
+
+/// Returns post-game details of the match
+#[tauri::command]
+pub async fn get_match_details(
+    api: State<'_, ApiClient>,
+    cache: State<'_, MatchCache>,
+    assets: State<'_, Assets>,
+    settings: State<'_, SettingsStore>,
+    match_id: u64,
+) -> Result<MatchDetails, AppError> {
+    let metadata = load_match_metadata(&api, &cache, match_id).await?;
+    let players = &metadata.match_info.players;
+    let mut hero_ids: Vec<u32> = players.iter().map(|player| player.hero_id).collect();
+    let mut item_ids: Vec<u32> = players
+        .iter()
+        .flat_map(|player| player.items.iter().map(|item| item.item_id))
+        .collect();
+    hero_ids.sort_unstable();
+    hero_ids.dedup();
+    item_ids.sort_unstable();
+    item_ids.dedup();
+
+    let mut details = MatchDetails::from(metadata.match_info);
+    details.assets = assets.resolve(&hero_ids, &item_ids, &settings.get().language);
+    Ok(details)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::FileTimes;
+    use std::time::{Duration, SystemTime};
+
+    use super::*;
+    use crate::api::generated::ItemPurchase;
+
+    fn player(team: u32, hero_id: u32, kills: u32, net_worth: u32) -> MatchPlayer {
+        MatchPlayer {
+            team,
+            hero_id,
+            kills,
+            net_worth,
+            ..MatchPlayer::default()
+        }
+    }
+
+    fn objective(team: u32, destroyed_time_s: u32) -> Objective {
+        Objective {
+            team,
+            destroyed_time_s,
+            team_objective_id: destroyed_time_s as i32,
+        }
+    }
+
+    fn metadata(match_id: u64) -> MatchMetadata {
+        let mut carry = player(1, 7, 12, 40_000);
+        carry.items = vec![
+            ItemPurchase {
+                game_time_s: 600,
+                item_id: 2,
+                ..ItemPurchase::default()
+            },
+            ItemPurchase {
+                game_time_s: 60,
+                item_id: 1,
+                ..ItemPurchase::default()
+            },
+        ];
+
+        MatchMetadata {
+            match_info: MatchInfo {
+                match_id,
+                duration_s: 1800,
+                winning_team: 1,
+                start_time: 1_700_000_000,
+                players: vec![
+                    player(0, 1, 5, 30_000),
+                    player(1, 2, 3, 20_000),
+                    player(0, 3, 8, 35_000),
+                    carry,
+                ],
+                objectives: vec![objective(0, 900), objective(1, 0), objective(0, 300)],
+                ..MatchInfo::default()
+            },
+        }
+    }
+
+    #[test]
+    fn summarizes_teams() {
+        let details = MatchDetails::from(metadata(1).match_info);
+
+        assert_eq!(details.match_id, 1);
+        assert_eq!(details.teams.len(), 2);
+        let (lost, won) = (&details.teams[0], &details.teams[1]);
+
+        assert_eq!(
+            (lost.team, lost.won, lost.kills, lost.net_worth),
+            (0, false, 13, 65_000)
+        );
+        assert_eq!(
+            (won.team, won.won, won.kills, won.net_worth),
+            (1, true, 15, 60_000)
+        );
+
+        // Richest player first, items in purchase order
+        let heroes: Vec<u32> = lost.players.iter().map(|player| player.hero_id).collect();
+        assert_eq!(heroes, [3, 1]);
+        let items: Vec<u32> = won.players[0]
+            .items
+            .iter()
+            .map(|item| item.item_id)
+            .collect();
+        assert_eq!(items, [1, 2]);
+
+        // Only destroyed objectives, in destruction order
+        let lost_at: Vec<u32> = lost
+            .objectives_lost
+            .iter()
+            .map(|objective| objective.destroyed_time_s)
+            .collect();
+        assert_eq!(lost_at, [300, 900]);
+        assert!(won.objectives_lost.is_empty());
+    }
+
+    fn cache(name: &str) -> MatchCache {
+        let dir = std::env::temp_dir().join(format!("deadlyze-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        MatchCache::new(dir)
+    }
+
+    #[test]
+    fn round_trips_cached_metadata() {
+        let cache = cache("match-cache");
+
+        tauri::async_runtime::block_on(async {
+            assert!(cache.get(1).await.is_none());
+            cache.put(&metadata(1)).await.unwrap();
+
+            let cached = cache.get(1).await.unwrap();
+            assert_eq!(cached.match_info.match_id, 1);
+            assert_eq!(cached.match_info.players.len(), 4);
+        });
+        let _ = std::fs::remove_dir_all(&cache.dir);
+    }
+
+    #[test]
+    fn evicts_least_recently_used_matches() {
+        let mut cache = cache("match-eviction");
+
+        tauri::async_runtime::block_on(async {
+            cache.put(&metadata(1)).await.unwrap();
+            let old = SystemTime::now() - Duration::from_secs(3600);
+            std::fs::File::options()
+                .write(true)
+                .open(cache.path(1))
+                .unwrap()
+                .set_times(FileTimes::new().set_accessed(old))
+                .unwrap();
+
+            // Room for one match; the next size check drops the older one
+            cache.max_bytes = std::fs::metadata(cache.path(1)).unwrap().len() * 3 / 2;
+            cache.puts.store(EVICT_EVERY - 1, Ordering::Relaxed);
+            cache.put(&metadata(2)).await.unwrap();
+
+            assert!(cache.get(1).await.is_none());
+            assert!(cache.get(2).await.is_some());
+        });
+        let _ = std::fs::remove_dir_all(&cache.dir);
+    }
+}
@@ -0,0 +1,515 @@
+//! Sanitized rendering of patch note HTML (`content_encoded`)
+//!
+//! The webview runs without CSP, so forum HTML never reaches the UI as-is.
+//! It is converted into Markdown with an allow-list of constructs: paragraphs,
+//! headings, bold/italic, lists, http(s) links and images from known hosts.
+//! Everything else is reduced to escaped plain text.
+
+/// Hosts images may be loaded from
+const ALLOWED_IMAGE_HOSTS: &[&str] = &[
+    "forums.playdeadlock.com",
+    "clan.akamai.steamstatic.com",
+    "cdn.akamai.steamstatic.com",
+    "shared.akamai.steamstatic.com",
+];
+
+/// Elements whose content is dropped entirely
+const DROPPED_ELEMENTS: &[&str] = &[
+    "script", "style", "iframe", "object", "embed", "noscript", "template", "svg", "math",
+];
+
+/// Single HTML token
+enum Token<'a> {
+    Text(&'a str),
+    Open {
+        name: String,
+        attrs: &'a str,
+        self_closing: bool,
+    },
+    Close {
+        name: String,
+    },
+}
+
+/// Splits HTML into text and tag tokens (comments and doctypes are skipped)
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            // Unterminated tag: treat the remainder as text
+            tokens.push(Token::Text(rest));
+            break;
+        };
+
+        let inner = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if inner.starts_with('!') || inner.starts_with('?') {
+            continue;
+        }
+
+        if let Some(name) = inner.strip_prefix('/') {
+            tokens.push(Token::Close {
+                name: name.trim().to_ascii_lowercase(),
+            });
+            continue;
+        }
+
+        let self_closing = inner.ends_with('/');
+        let inner = inner.trim_end_matches('/');
+        let name_end = inner
+            .find(|c: char| c.is_whitespace())
+            .unwrap_or(inner.len());
+
+        tokens.push(Token::Open {
+            name: inner[..name_end].to_ascii_lowercase(),
+            attrs: &inner[name_end..],
+            self_closing,
+        });
+    }
+
+    tokens
+}
+
+/// Extracts attribute value from raw attribute string (`href="..."`, `src='...'`, `alt=x`)
+fn attribute(attrs: &str, name: &str) -> Option<String> {
+    let mut rest = attrs;
+
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            return None;
+        }
+
+        let key_end = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let key = &rest[..key_end];
+        rest = rest[key_end..].trim_start();
+
+        let value = if let Some(after_eq) = rest.strip_prefix('=') {
+            let after_eq = after_eq.trim_start();
+            let (value, remainder) = match after_eq.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let body = &after_eq[1..];
+                    let close = body.find(quote).unwrap_or(body.len());
+                    (&body[..close], body.get(close + 1..).unwrap_or(""))
+                }
+                _ => {
+                    let close = after_eq.find(char::is_whitespace).unwrap_or(after_eq.len());
+                    (&after_eq[..close], &after_eq[close..])
+                }
+            };
+            rest = remainder;
+            value
+        } else {
+            ""
+        };
+
+        if key.eq_ignore_ascii_case(name) {
+            return Some(decode_entities(value));
+        }
+    }
+}
+
+/// Decodes HTML character references (named subset and numeric)
+fn decode_entities(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(amp) = rest.find('&') {
+        result.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+
+        let decoded = rest.find(';').filter(|&end| end <= 10).and_then(|end| {
+            let entity = &rest[1..end];
+            let ch = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+            ch.map(|ch| (ch, end))
+        });
+
+        match decoded {
+            Some((ch, end)) => {
+                result.push(ch);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Escapes characters that carry meaning in Markdown or inline HTML
+fn escape_markdown(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if matches!(
+            ch,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '(' | ')' | '#' | '!' | '<' | '>' | '|' | '~'
+        ) {
+            result.push('\\');
+        }
+        result.push(ch);
+    }
+    result
+}
+
+/// Validates link target: only absolute http(s) URLs are kept
+fn sanitize_url(url: &str) -> Option<String> {
+    let url = url.trim();
+    let lower = url.to_ascii_lowercase();
+
+    if !(lower.starts_with("https://") || lower.starts_with("http://")) {
+        return None;
+    }
+
+    // Percent-encode characters that could break out of the Markdown link syntax
+    let mut encoded = String::with_capacity(url.len());
+    for ch in url.chars() {
+        match ch {
+            ' ' => encoded.push_str("%20"),
+            '(' => encoded.push_str("%28"),
+            ')' => encoded.push_str("%29"),
+            '<' => encoded.push_str("%3C"),
+            '>' => encoded.push_str("%3E"),
+            '"' => encoded.push_str("%22"),
+            c if c.is_control() => {}
+            c => encoded.push(c),
+        }
+    }
+
+    Some(encoded)
+}
+
+/// Checks that image URL points to one of the allowed hosts
+fn is_allowed_image(url: &str) -> bool {
+    let Some(rest) = url.strip_prefix("https://") else {
+        return false;
+    };
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or("");
+
+    // Reject credentials and explicit ports (`user@host`, `host:8080`)
+    if authority.contains('@') || authority.contains(':') {
+        return false;
+    }
+
+    ALLOWED_IMAGE_HOSTS
+        .iter()
+        .any(|host| authority.eq_ignore_ascii_case(host))
+}
+
+/// Open list while rendering
+struct List {
+    ordered: bool,
+    next_index: usize,
+}
+
+/// Markdown writer state
+#[derive(Default)]
+struct Renderer {
+    output: String,
+    lists: Vec<List>,
+    /// Open links: target URL (None when the link was rejected)
+    links: Vec<Option<String>>,
+    /// Depth of dropped elements (script, style, ...)
+    dropped_depth: usize,
+}
+
+impl Renderer {
+    fn ends_with_newline(&self) -> bool {
+        self.output.is_empty() || self.output.ends_with('\n')
+    }
+
+    fn line_break(&mut self) {
+        if !self.ends_with_newline() {
+            self.output.push('\n');
+        }
+    }
+
+    fn paragraph_break(&mut self) {
+        if self.output.is_empty() {
+            return;
+        }
+        self.line_break();
+        if !self.output.ends_with("\n\n") {
+            self.output.push('\n');
+        }
+    }
+
+    fn text(&mut self, raw: &str) {
+        let decoded = decode_entities(raw);
+        let mut collapsed = String::with_capacity(decoded.len());
+        let mut last_space = self.ends_with_newline() || self.output.ends_with(' ');
+
+        for ch in decoded.chars() {
+            if ch.is_whitespace() {
+                if !last_space {
+                    collapsed.push(' ');
+                    last_space = true;
+                }
+            } else {
+                collapsed.push(ch);
+                last_space = false;
+            }
+        }
+
+        self.output.push_str(&escape_markdown(&collapsed));
+    }
+
+    fn open(&mut self, name: &str, attrs: &str) {
+        match name {
+            "b" | "strong" => self.output.push_str("**"),
+            "i" | "em" => self.output.push('*'),
+            "br" => self.line_break(),
+            "p" | "div" | "blockquote" | "table" => self.paragraph_break(),
+            "tr" => self.line_break(),
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.paragraph_break();
+                let level = name[1..].parse::<usize>().unwrap_or(1);
+                self.output.push_str(&"#".repeat(level));
+                self.output.push(' ');
+            }
+            "ul" | "ol" => {
+                if self.lists.is_empty() {
+                    self.paragraph_break();
+                }
+                self.lists.push(List {
+                    ordered: name == "ol",
+                    next_index: 1,
+                });
+            }
+            "li" => {
+                self.line_break();
+                let depth = self.lists.len().saturating_sub(1);
+                self.output.push_str(&"  ".repeat(depth));
+                match self.lists.last_mut() {
+                    Some(list) if list.ordered => {
+                        self.output.push_str(&format!("{}. ", list.next_index));
+                        list.next_index += 1;
+                    }
+                    _ => self.output.push_str("- "),
+                }
+            }
+            "a" => {
+                let href = attribute(attrs, "href").and_then(|href| sanitize_url(&href));
+                if href.is_some() {
+                    self.output.push('[');
+                }
+                self.links.push(href);
+            }
+            "img" => {
+                let src = attribute(attrs, "src")
+                    .and_then(|src| sanitize_url(&src))
+                    .filter(|src| is_allowed_image(src));
+                if let Some(src) = src {
+                    let alt = attribute(attrs, "alt").unwrap_or_default();
+                    self.output
+                        .push_str(&format!("![{}]({})", escape_markdown(alt.trim()), src));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn close(&mut self, name: &str) {
+        match name {
+            "b" | "strong" => self.output.push_str("**"),
+            "i" | "em" => self.output.push('*'),
+            "p" | "div" | "blockquote" | "table" => self.paragraph_break(),
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => self.paragraph_break(),
+            "ul" | "ol" => {
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.paragraph_break();
+                }
+            }
+            "a" => {
+                if let Some(Some(href)) = self.links.pop() {
+                    self.output.push_str(&format!("]({})", href));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn finish(mut self) -> String {
+        // Close links left open by malformed HTML
+        while let Some(link) = self.links.pop() {
+            if let Some(href) = link {
+                self.output.push_str(&format!("]({})", href));
+            }
+        }
+
+        let lines: Vec<&str> = self.output.lines().map(str::trim_end).collect();
+        lines.join("\n").trim().to_string()
+    }
+}
+
+/// Converts patch note HTML into sanitized Markdown
+pub fn render_markdown(html: &str) -> String {
+    let mut renderer = Renderer::default();
+
+    for token in tokenize(html) {
+        match token {
+            Token::Open {
+                name, self_closing, ..
+            } if DROPPED_ELEMENTS.contains(&name.as_str()) => {
+                if !self_closing {
+                    renderer.dropped_depth += 1;
+                }
+            }
+            Token::Close { name } if DROPPED_ELEMENTS.contains(&name.as_str()) => {
+                renderer.dropped_depth = renderer.dropped_depth.saturating_sub(1);
+            }
+            _ if renderer.dropped_depth > 0 => {}
+            Token::Text(text) => renderer.text(text),
+            Token::Open {
+                name,
+                attrs,
+                self_closing,
+            } => {
+                renderer.open(&name, attrs);
+                if self_closing && name != "br" && name != "img" {
+                    renderer.close(&name);
+                }
+            }
+            Token::Close { name } => renderer.close(&name),
+        }
+    }
+
+    renderer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_http_links() {
+        assert_eq!(
+            render_markdown(r#"<a href="https://example.com/notes">Notes</a>"#),
+            "[Notes](https://example.com/notes)"
+        );
+    }
+
+    #[test]
+    fn drops_javascript_links() {
+        for html in [
+            r#"<a href="javascript:alert(1)">click</a>"#,
+            r#"<a href=" JaVaScRiPt:alert(1)">click</a>"#,
+            r#"<a href='data:text/html;base64,PHNjcmlwdD4='>click</a>"#,
+            r#"<a href="/relative">click</a>"#,
+        ] {
+            assert_eq!(render_markdown(html), "click", "{}", html);
+        }
+    }
+
+    #[test]
+    fn drops_entity_encoded_javascript_links() {
+        for html in [
+            r#"<a href="&#106;avascript:alert(1)">click</a>"#,
+            r#"<a href="&#x6A;avascript&#58;alert(1)">click</a>"#,
+            r#"<a href="java&#x09;script:alert(1)">click</a>"#,
+        ] {
+            assert_eq!(render_markdown(html), "click", "{}", html);
+        }
+    }
+
+    #[test]
+    fn encodes_characters_breaking_link_syntax() {
+        assert_eq!(
+            render_markdown(r#"<a href="https://example.com/a b)(x)">x</a>"#),
+            "[x](https://example.com/a%20b%29%28x%29)"
+        );
+    }
+
+    #[test]
+    fn keeps_images_from_allowed_hosts_only() {
+        assert_eq!(
+            render_markdown(
+                r#"<img src="https://clan.akamai.steamstatic.com/images/patch.png" alt="Patch [1]">"#
+            ),
+            r"![Patch \[1\]](https://clan.akamai.steamstatic.com/images/patch.png)"
+        );
+
+        for html in [
+            r#"<img src="https://evil.example.com/track.png">"#,
+            r#"<img src="http://forums.playdeadlock.com/a.png">"#,
+            r#"<img src="https://forums.playdeadlock.com@evil.example.com/a.png">"#,
+            r#"<img src="https://forums.playdeadlock.com:8080/a.png">"#,
+            r#"<img src="https://forums.playdeadlock.com.evil.example.com/a.png">"#,
+            r#"<img src="javascript:alert(1)">"#,
+        ] {
+            assert_eq!(render_markdown(html), "", "{}", html);
+        }
+    }
+
+    #[test]
+    fn drops_script_and_style_content() {
+        let html = "<p>Before</p><script>alert('x')</script>\
+                    <style>p { color: red }</style><p>After</p>";
+
+        assert_eq!(render_markdown(html), "Before\n\nAfter");
+    }
+
+    #[test]
+    fn self_closing_dropped_element_keeps_following_content() {
+        assert_eq!(
+            render_markdown(r#"<script src="https://evil.example.com/x.js"/><p>Visible</p>"#),
+            "Visible"
+        );
+    }
+
+    #[test]
+    fn escapes_markdown_and_html_in_text() {
+        assert_eq!(
+            render_markdown("<p>*bold* [link](https://x.y) &lt;img src=x&gt; `code`</p>"),
+            r"\*bold\* \[link\]\(https://x.y\) \<img src=x\> \`code\`"
+        );
+    }
+
+    #[test]
+    fn renders_formatting_and_lists() {
+        let html = "<h2>Heroes</h2><ul><li><b>Abrams</b>: more health</li>\
+                    <li>Haze<ol><li>first</li><li>second</li></ol></li></ul>";
+
+        assert_eq!(
+            render_markdown(html),
+            "## Heroes\n\n- **Abrams**: more health\n- Haze\n  1. first\n  2. second"
+        );
+    }
+}
@@ -0,0 +1,154 @@
+//! Patch notes (GET /v1/patches)
+//!
+//! Parsed result of the RSS feed from the official forum.
+
+mod content;
+mod rss;
+mod timeline;
+
+pub use timeline::{PatchPeriod, PatchSelector, PatchTimeline};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tauri::State;
+
+use crate::api::ApiClient;
+use crate::error::AppError;
+
+const ENDPOINT: &str = "/v1/patches";
+
+/// Notice source of patch feed problems
+const FEED_NOTICE: &str = "patch_feed";
+
+/// Patch note models come from the OpenAPI spec
+pub use crate::api::generated::{PatchCategory, PatchGuid, PatchNote};
+
+/// Patch note with its publication date parsed
+#[derive(Debug, Clone)]
+pub struct Patch {
+    pub note: PatchNote,
+    pub published: DateTime<Utc>,
+}
+
+impl Patch {
+    /// Parses the publication date (RFC 2822, e.g. "Thu, 10 Apr 2025 23:27:46 +0000")
+    pub fn parse(note: PatchNote, source: &str) -> Result<Self, AppError> {
+        let published = DateTime::parse_from_rfc2822(note.pub_date.trim())
+            .map_err(|e| {
+                AppError::invalid_response(
+                    source,
+                    format!("item '{}' has invalid pubDate: {}", note.title, e),
+                )
+            })?
+            .with_timezone(&Utc);
+        Ok(Self { note, published })
+    }
+}
+
+/// Patch note as sent to the UI: HTML content is replaced by sanitized Markdown
+#[derive(Debug, Clone, Serialize)]
+pub struct PatchNoteView {
+    pub guid: String,
+    pub title: String,
+    pub author: String,
+    pub category: String,
+    pub link: String,
+    pub pub_date: DateTime<Utc>,
+    pub comments: u32,
+    pub content_markdown: String,
+}
+
+impl From<&Patch> for PatchNoteView {
+    fn from(patch: &Patch) -> Self {
+        let note = &patch.note;
+        Self {
+            guid: note.guid.text.clone(),
+            title: note.title.clone(),
+            author: note.dc_creator.clone(),
+            category: note.category.text.clone(),
+            link: note.link.clone(),
+            pub_date: patch.published,
+            comments: note.slash_comments.trim().parse().unwrap_or(0),
+            content_markdown: content::render_markdown(&note.content_encoded),
+        }
+    }
+}
+
+/// Sorts patch notes chronologically (oldest first)
+pub fn sort_chronologically(patches: &mut [Patch]) {
+    patches.sort_by_key(|patch| patch.published);
+}
+
+/// Parses API patch notes; notes with an invalid date are skipped and recorded
+fn parse_notes(api: &ApiClient, notes: Vec<PatchNote>) -> Vec<Patch> {
+    notes
+        .into_iter()
+        .filter_map(|note| match Patch::parse(note, ENDPOINT) {
+            Ok(patch) => Some(patch),
+            Err(e) => {
+                api.notices().record(FEED_NOTICE, e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Fetches patch notes, ordered oldest first
+///
+/// While the API is unavailable the forum RSS feed is read instead, merged
+/// with the last cached API response by GUID. Feed problems are recorded as
+/// API notices; the API error is returned only when the feed fails too.
+pub async fn fetch_patch_notes(api: &ApiClient) -> Result<Vec<Patch>, AppError> {
+    let mut patches = match api.get_patch_notes().await {
+        Ok(notes) => parse_notes(api, notes),
+        Err(api_error) => match fetch_feed(api).await {
+            Ok(feed_patches) => {
+                let cached = api
+                    .get_cached::<Vec<PatchNote>>(ENDPOINT, &[])
+                    .unwrap_or_default();
+                rss::merge_by_guid(parse_notes(api, cached), feed_patches)
+            }
+            Err(feed_error) => {
+                api.notices().record(FEED_NOTICE, feed_error);
+                return Err(api_error);
+            }
+        },
+    };
+
+    sort_chronologically(&mut patches);
+    Ok(patches)
+}
+
+/// Reads the feed from the location in the API settings
+async fn fetch_feed(api: &ApiClient) -> Result<Vec<Patch>, AppError> {
+    let source = rss::FeedSource::from_settings(api.patch_feed().as_deref());
+    let feed = rss::parse_feed(&source.load(&api.http()).await?)?;
+    for skipped in feed.skipped {
+        api.notices().record(FEED_NOTICE, skipped);
+    }
+    Ok(feed.patches)
+}
+
+/// Returns patch notes with sanitized content, newest first
+#[tauri::command]
+pub async fn get_patch_notes(api: State<'_, ApiClient>) -> Result<Vec<PatchNoteView>, AppError> {
+    let patches = fetch_patch_notes(&api).await?;
+    Ok(patches.iter().rev().map(PatchNoteView::from).collect())
+}
+
+/// Returns patch timeline (when each patch started and ended)
+#[tauri::command]
+pub async fn get_patch_timeline(api: State<'_, ApiClient>) -> Result<PatchTimeline, AppError> {
+    let patches = fetch_patch_notes(&api).await?;
+    Ok(PatchTimeline::from_patches(&patches))
+}
+
+/// Returns the period of the selected patch (current or previous)
+#[tauri::command]
+pub async fn get_patch_period(
+    api: State<'_, ApiClient>,
+    patch: PatchSelector,
+) -> Result<Option<PatchPeriod>, AppError> {
+    let patches = fetch_patch_notes(&api).await?;
+    Ok(PatchTimeline::from_patches(&patches).select(patch).cloned())
+}
@@ -0,0 +1,375 @@
+//! Fallback ingestion of the official forum RSS feed
+//!
+//! `/v1/patches` is a parsed mirror of this feed; reading it directly keeps
+//! patch notes available when the Deadlock API is down.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use super::{Patch, PatchCategory, PatchGuid, PatchNote};
+use crate::error::AppError;
+
+/// Official forum changelog feed
+pub const DEFAULT_FEED_URL: &str = "https://forums.playdeadlock.com/forums/changelog.10/index.rss";
+
+/// Location of the RSS feed
+#[derive(Debug, Clone, PartialEq)]
+pub enum FeedSource {
+    Url(String),
+    File(PathBuf),
+}
+
+impl FeedSource {
+    /// Parses feed location: `http(s)://` prefix means URL, anything else is a file path
+    pub fn parse(location: &str) -> Self {
+        let location = location.trim();
+        if location.starts_with("http://") || location.starts_with("https://") {
+            Self::Url(location.to_string())
+        } else {
+            Self::File(PathBuf::from(location))
+        }
+    }
+
+    /// Feed location from the API settings, falling back to the official feed
+    pub fn from_settings(location: Option<&str>) -> Self {
+        Self::parse(location.unwrap_or(DEFAULT_FEED_URL))
+    }
+
+    /// Loads raw feed XML
+    pub async fn load(&self, http: &reqwest::Client) -> Result<String, AppError> {
+        match self {
+            Self::Url(url) => {
+                let response = http
+                    .get(url)
+                    .send()
+                    .await
+                    .map_err(|e| AppError::network(url, e))?;
+                let status = response.status();
+
+                if !status.is_success() {
+                    return Err(AppError::Http {
+                        url: url.clone(),
+                        status: status.as_u16(),
+                    });
+                }
+
+                response.text().await.map_err(|e| AppError::network(url, e))
+            }
+            Self::File(path) => tokio::fs::read_to_string(path)
+                .await
+                .map_err(|e| AppError::storage(path, e)),
+        }
+    }
+}
+
+/// Item fields collected while reading the XML
+#[derive(Default)]
+struct ItemBuilder {
+    author: String,
+    category: Option<PatchCategory>,
+    content_encoded: String,
+    dc_creator: String,
+    guid: Option<PatchGuid>,
+    link: String,
+    pub_date: String,
+    slash_comments: String,
+    title: String,
+}
+
+impl ItemBuilder {
+    fn build(self) -> Result<Patch, AppError> {
+        // GUID is the identity used for deduplication
+        let guid = self
+            .guid
+            .filter(|guid| !guid.text.is_empty())
+            .ok_or_else(|| {
+                AppError::invalid_response("rss", format!("item '{}' has no guid", self.title))
+            })?;
+
+        let note = PatchNote {
+            author: self.author,
+            category: self.category.unwrap_or_default(),
+            content_encoded: self.content_encoded,
+            dc_creator: self.dc_creator,
+            guid,
+            link: self.link,
+            pub_date: self.pub_date,
+            slash_comments: self.slash_comments,
+            title: self.title,
+        };
+        Patch::parse(note, "rss")
+    }
+}
+
+/// Parsed feed: valid items and the reasons other items were skipped
+#[derive(Debug, Default)]
+pub struct Feed {
+    pub patches: Vec<Patch>,
+    pub skipped: Vec<AppError>,
+}
+
+/// Parses RSS 2.0 XML into patch notes
+///
+/// Items without guid or with an invalid date are skipped; only broken XML
+/// fails the whole feed.
+pub fn parse_feed(xml: &str) -> Result<Feed, AppError> {
+    let mut reader = Reader::from_str(xml);
+
+    let mut feed = Feed::default();
+    let mut item: Option<ItemBuilder> = None;
+    let mut field: Option<String> = None;
+    let mut text = String::new();
+
+    loop {
+        let event = reader.read_event().map_err(|e| {
+            AppError::invalid_response(
+                "rss",
+                format!("invalid XML at {}: {}", reader.buffer_position(), e),
+            )
+        })?;
+
+        match event {
+            Event::Start(start) => {
+                let name = qualified_name(&start);
+                if name == "item" {
+                    item = Some(ItemBuilder::default());
+                } else if let Some(current) = item.as_mut() {
+                    open_field(current, &start, &name);
+                    field = Some(name);
+                    text.clear();
+                }
+            }
+            // Self-closing element (`<guid/>`) has no text
+            Event::Empty(start) => {
+                if let Some(current) = item.as_mut() {
+                    let name = qualified_name(&start);
+                    open_field(current, &start, &name);
+                    assign_field(current, &name, "");
+                }
+            }
+            Event::Text(content) if field.is_some() => {
+                let content = content
+                    .unescape()
+                    .map_err(|e| AppError::invalid_response("rss", e))?;
+                text.push_str(&content);
+            }
+            Event::CData(content) if field.is_some() => {
+                let content = content
+                    .decode()
+                    .map_err(|e| AppError::invalid_response("rss", e))?;
+                text.push_str(&content);
+            }
+            Event::End(end) => {
+                let name = String::from_utf8_lossy(end.name().as_ref()).into_owned();
+                if name == "item" {
+                    if let Some(builder) = item.take() {
+                        match builder.build() {
+                            Ok(patch) => feed.patches.push(patch),
+                            Err(e) => feed.skipped.push(e),
+                        }
+                    }
+                } else if let (Some(current), Some(open)) = (item.as_mut(), field.as_deref()) {
+                    if open == name {
+                        assign_field(current, &name, text.trim());
+                        field = None;
+                    }
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(feed)
+}
+
+/// Prepares fields that carry attributes
+fn open_field(item: &mut ItemBuilder, start: &BytesStart, name: &str) {
+    match name {
+        "guid" => {
+            item.guid = Some(PatchGuid {
+                is_perma_link: attribute(start, "isPermaLink").is_none_or(|value| value == "true"),
+                text: String::new(),
+            });
+        }
+        "category" => {
+            item.category = Some(PatchCategory {
+                domain: attribute(start, "domain").unwrap_or_default(),
+                text: String::new(),
+            });
+        }
+        _ => {}
+    }
+}
+
+fn qualified_name(start: &BytesStart) -> String {
+    String::from_utf8_lossy(start.name().as_ref()).into_owned()
+}
+
+fn attribute(start: &BytesStart, name: &str) -> Option<String> {
+    start
+        .try_get_attribute(name)
+        .ok()
+        .flatten()
+        .and_then(|attr| attr.unescape_value().ok())
+        .map(|value| value.into_owned())
+}
+
+fn assign_field(item: &mut ItemBuilder, name: &str, value: &str) {
+    let value = value.to_string();
+    match name {
+        "title" => item.title = value,
+        "link" => item.link = value,
+        "pubDate" => item.pub_date = value,
+        "author" => item.author = value,
+        "dc:creator" => item.dc_creator = value,
+        "content:encoded" => item.content_encoded = value,
+        "slash:comments" => item.slash_comments = value,
+        "guid" => {
+            if let Some(guid) = item.guid.as_mut() {
+                guid.text = value;
+            }
+        }
+        "category" => {
+            if let Some(category) = item.category.as_mut() {
+                category.text = value;
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Merges feed items into API data: API entries win, feed adds GUIDs the API doesn't know yet
+pub fn merge_by_guid(api_patches: Vec<Patch>, feed_patches: Vec<Patch>) -> Vec<Patch> {
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut merged = Vec::with_capacity(api_patches.len() + feed_patches.len());
+
+    for patch in api_patches.into_iter().chain(feed_patches) {
+        if seen.insert(patch.note.guid.text.clone()) {
+            merged.push(patch);
+        }
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEED_FIXTURE: &str = include_str!("../../tests/fixtures/changelog_feed.xml");
+    const MALFORMED_FIXTURE: &str =
+        include_str!("../../tests/fixtures/changelog_feed_malformed.xml");
+
+    #[test]
+    fn parses_items_from_fixture() {
+        let patches = parse_feed(FEED_FIXTURE).unwrap().patches;
+
+        assert_eq!(patches.len(), 3);
+
+        let first = &patches[0].note;
+        assert_eq!(first.title, "Gameplay Update - 05-08-2025");
+        assert_eq!(first.dc_creator, "Yoshi");
+        assert_eq!(first.author, "invalid@example.com (Yoshi)");
+        assert_eq!(first.guid.text, "59871");
+        assert!(!first.guid.is_perma_link);
+        assert_eq!(first.category.text, "Changelog");
+        assert_eq!(
+            first.category.domain,
+            "https://forums.playdeadlock.com/forums/changelog.10/"
+        );
+        assert_eq!(first.slash_comments, "12");
+        assert_eq!(
+            patches[0].published.to_rfc3339(),
+            "2025-05-08T22:44:01+00:00"
+        );
+        assert!(first.content_encoded.contains("<b>Abrams</b>"));
+    }
+
+    #[test]
+    fn unescapes_text_and_keeps_cdata_html() {
+        let patches = parse_feed(FEED_FIXTURE).unwrap().patches;
+
+        let escaped = &patches[1].note;
+        assert_eq!(escaped.title, "Hotfix & Balance Changes");
+        assert!(escaped.content_encoded.starts_with("<ul>"));
+    }
+
+    #[test]
+    fn normalizes_pub_date_offsets() {
+        let patches = parse_feed(FEED_FIXTURE).unwrap().patches;
+
+        assert_eq!(
+            patches[2].published.to_rfc3339(),
+            "2025-04-10T21:27:46+00:00"
+        );
+    }
+
+    #[test]
+    fn skips_malformed_items() {
+        let feed = parse_feed(MALFORMED_FIXTURE).unwrap();
+
+        assert_eq!(feed.patches.len(), 1);
+        assert_eq!(feed.patches[0].note.guid.text, "59871");
+        assert_eq!(feed.skipped.len(), 3);
+        assert!(feed
+            .skipped
+            .iter()
+            .all(|error| error.code() == "INVALID_RESPONSE"));
+    }
+
+    #[test]
+    fn skips_items_with_empty_guid() {
+        let feed = parse_feed(MALFORMED_FIXTURE).unwrap();
+
+        let reasons: Vec<String> = feed.skipped.iter().map(ToString::to_string).collect();
+        assert!(
+            reasons
+                .iter()
+                .any(|reason| reason.contains("'Hotfix with empty guid' has no guid")),
+            "{:?}",
+            reasons
+        );
+    }
+
+    #[test]
+    fn fails_on_broken_xml() {
+        let error = parse_feed("<rss><channel><item><title>x</item></rss>").unwrap_err();
+
+        assert_eq!(error.code(), "INVALID_RESPONSE");
+    }
+
+    #[test]
+    fn merge_prefers_api_entries_and_deduplicates_by_guid() {
+        let feed = parse_feed(FEED_FIXTURE).unwrap().patches;
+        let mut api = vec![feed[1].clone()];
+        api[0].note.title = "From API".to_string();
+
+        let merged = merge_by_guid(api, feed);
+
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged[0].note.title, "From API");
+        assert_eq!(
+            merged
+                .iter()
+                .filter(|patch| patch.note.guid.text == "59790")
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn parses_feed_source_locations() {
+        assert_eq!(
+            FeedSource::parse("https://example.com/index.rss"),
+            FeedSource::Url("https://example.com/index.rss".to_string())
+        );
+        assert_eq!(
+            FeedSource::parse("C:/feeds/changelog.xml"),
+            FeedSource::File(PathBuf::from("C:/feeds/changelog.xml"))
+        );
+    }
+}
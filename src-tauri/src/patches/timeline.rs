@@ -0,0 +1,145 @@
+//! Patch timeline: patch N was live from T until T' (publication of patch N+1)
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::Patch;
+
+/// Period during which a single patch was live
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatchPeriod {
+    pub guid: String,
+    pub title: String,
+    pub started_at: DateTime<Utc>,
+    /// `None` for the current (latest) patch
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+impl PatchPeriod {
+    /// Period bounds as Unix timestamps (for `min_unix_timestamp`/`max_unix_timestamp` filters)
+    pub fn unix_range(&self) -> (i64, Option<i64>) {
+        (
+            self.started_at.timestamp(),
+            self.ended_at.map(|end| end.timestamp()),
+        )
+    }
+}
+
+/// Patch selector used by stats queries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PatchSelector {
+    Current,
+    Previous,
+}
+
+/// Chronological list of patch periods (oldest first)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PatchTimeline {
+    pub periods: Vec<PatchPeriod>,
+}
+
+impl PatchTimeline {
+    /// Builds timeline from patch notes in any order
+    pub fn from_patches(patches: &[Patch]) -> Self {
+        let mut sorted: Vec<&Patch> = patches.iter().collect();
+        sorted.sort_by_key(|patch| patch.published);
+
+        let periods = sorted
+            .iter()
+            .enumerate()
+            .map(|(index, patch)| PatchPeriod {
+                guid: patch.note.guid.text.clone(),
+                title: patch.note.title.clone(),
+                started_at: patch.published,
+                ended_at: sorted.get(index + 1).map(|next| next.published),
+            })
+            .collect();
+
+        Self { periods }
+    }
+
+    /// Latest patch
+    pub fn current(&self) -> Option<&PatchPeriod> {
+        self.periods.last()
+    }
+
+    /// Patch before the latest one
+    pub fn previous(&self) -> Option<&PatchPeriod> {
+        self.periods.iter().rev().nth(1)
+    }
+
+    pub fn select(&self, selector: PatchSelector) -> Option<&PatchPeriod> {
+        match selector {
+            PatchSelector::Current => self.current(),
+            PatchSelector::Previous => self.previous(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::patches::{PatchGuid, PatchNote};
+
+    fn patch(guid: &str, pub_date: &str) -> Patch {
+        let note = PatchNote {
+            guid: PatchGuid {
+                text: guid.into(),
+                ..PatchGuid::default()
+            },
+            title: format!("Patch {}", guid),
+            pub_date: pub_date.into(),
+            ..PatchNote::default()
+        };
+        Patch::parse(note, "test").unwrap()
+    }
+
+    fn timeline() -> PatchTimeline {
+        PatchTimeline::from_patches(&[
+            patch("3", "Thu, 08 May 2025 22:44:01 +0000"),
+            patch("1", "Mon, 10 Mar 2025 20:00:00 +0000"),
+            patch("2", "Thu, 10 Apr 2025 23:27:46 +0200"),
+        ])
+    }
+
+    #[test]
+    fn builds_periods_in_chronological_order() {
+        let timeline = timeline();
+        let guids: Vec<&str> = timeline
+            .periods
+            .iter()
+            .map(|period| period.guid.as_str())
+            .collect();
+        assert_eq!(guids, ["1", "2", "3"]);
+
+        // Each patch ends when the next one is published
+        let second = &timeline.periods[1];
+        assert_eq!(second.title, "Patch 2");
+        assert_eq!(second.started_at.to_rfc3339(), "2025-04-10T21:27:46+00:00");
+        assert_eq!(second.ended_at, Some(timeline.periods[2].started_at));
+        assert_eq!(
+            second.unix_range(),
+            (1744320466, Some(timeline.periods[2].started_at.timestamp()))
+        );
+        assert_eq!(timeline.periods[2].ended_at, None);
+    }
+
+    #[test]
+    fn selects_current_and_previous_patch() {
+        let timeline = timeline();
+
+        assert_eq!(timeline.select(PatchSelector::Current).unwrap().guid, "3");
+        assert_eq!(timeline.select(PatchSelector::Previous).unwrap().guid, "2");
+    }
+
+    #[test]
+    fn selects_nothing_without_enough_patches() {
+        let empty = PatchTimeline::from_patches(&[]);
+        assert!(empty.select(PatchSelector::Current).is_none());
+
+        let single = PatchTimeline::from_patches(&[patch("1", "Mon, 10 Mar 2025 20:00:00 +0000")]);
+        assert_eq!(single.select(PatchSelector::Current).unwrap().guid, "1");
+        assert!(single.select(PatchSelector::Previous).is_none());
+    }
+}
@@ -0,0 +1,346 @@
+//! Steam profile change history (persona names, avatars, country codes)
+//!
+//! Profiles are recorded on every fetch, so the file is written in the
+//! background shortly after the last change instead of on each call.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::SteamProfile;
+use crate::error::AppError;
+use crate::storage;
+
+/// History file in the app data directory
+pub const HISTORY_FILE: &str = "profile_history.json";
+
+/// Notice source of history load and write failures
+pub const HISTORY_NOTICE: &str = "profile_history";
+
+/// Snapshots kept per account (oldest are dropped)
+const MAX_SNAPSHOTS: usize = 32;
+
+/// Accounts kept (least recently seen are dropped)
+const MAX_ACCOUNTS: usize = 5000;
+
+/// Delay before writing recorded changes (batches bursts of fetches)
+const SAVE_DELAY: Duration = Duration::from_secs(2);
+
+/// Tracked profile state; a new snapshot is stored only when it changes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileSnapshot {
+    pub personaname: String,
+    pub avatarfull: String,
+    pub countrycode: Option<String>,
+    /// `last_updated` of the latest profile with this state
+    pub last_updated: i64,
+    pub first_seen: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+}
+
+impl ProfileSnapshot {
+    fn from_profile(profile: &SteamProfile, seen_at: DateTime<Utc>) -> Self {
+        Self {
+            personaname: profile.personaname.clone(),
+            avatarfull: profile.avatarfull.clone(),
+            countrycode: profile.countrycode.clone(),
+            last_updated: profile.last_updated,
+            first_seen: seen_at,
+            last_seen: seen_at,
+        }
+    }
+
+    fn same_state(&self, profile: &SteamProfile) -> bool {
+        self.personaname == profile.personaname
+            && self.avatarfull == profile.avatarfull
+            && self.countrycode == profile.countrycode
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileField {
+    Personaname,
+    Avatar,
+    Countrycode,
+}
+
+/// Single change between two consecutive snapshots
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileChange {
+    pub field: ProfileField,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// Account history as sent to the UI
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountHistory {
+    pub account_id: u32,
+    /// Oldest first
+    pub snapshots: Vec<ProfileSnapshot>,
+    /// Oldest first
+    pub changes: Vec<ProfileChange>,
+    /// Distinct persona names, most recent first
+    pub known_names: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct HistoryData {
+    accounts: HashMap<u32, Vec<ProfileSnapshot>>,
+}
+
+impl HistoryData {
+    fn record(&mut self, profiles: &[SteamProfile], now: DateTime<Utc>) {
+        for profile in profiles {
+            let snapshots = self.accounts.entry(profile.account_id).or_default();
+            match snapshots.last_mut() {
+                Some(last) if last.same_state(profile) => {
+                    last.last_updated = last.last_updated.max(profile.last_updated);
+                    last.last_seen = now;
+                }
+                _ => snapshots.push(ProfileSnapshot::from_profile(profile, now)),
+            }
+            if snapshots.len() > MAX_SNAPSHOTS {
+                snapshots.drain(..snapshots.len() - MAX_SNAPSHOTS);
+            }
+        }
+
+        if self.accounts.len() > MAX_ACCOUNTS {
+            let mut last_seen: Vec<(DateTime<Utc>, u32)> = self
+                .accounts
+                .iter()
+                .filter_map(|(id, snapshots)| Some((snapshots.last()?.last_seen, *id)))
+                .collect();
+            last_seen.sort_unstable();
+            let excess = self.accounts.len() - MAX_ACCOUNTS;
+            for (_, id) in last_seen.into_iter().take(excess) {
+                self.accounts.remove(&id);
+            }
+        }
+    }
+}
+
+/// Profile history store (managed as Tauri state)
+pub struct ProfileHistory {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    path: PathBuf,
+    data: Mutex<HistoryData>,
+    /// Set while a background write is scheduled
+    save_pending: AtomicBool,
+    /// Serializes file writes
+    write_lock: Mutex<()>,
+    /// Failure of the last background write, reported by the next `record`
+    write_error: Mutex<Option<AppError>>,
+}
+
+impl Inner {
+    fn save(&self) -> Result<(), AppError> {
+        let _guard = self.write_lock.lock().unwrap();
+        let data = self.data.lock().unwrap().clone();
+        storage::save_json(&self.path, &data)
+    }
+}
+
+impl ProfileHistory {
+    fn new(path: PathBuf, data: HistoryData) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                path,
+                data: Mutex::new(data),
+                save_pending: AtomicBool::new(false),
+                write_lock: Mutex::new(()),
+                write_error: Mutex::new(None),
+            }),
+        }
+    }
+
+    /// Loads history file; a corrupt file is moved aside and reported, and a
+    /// new history is started in the returned store
+    pub fn load(path: PathBuf) -> (Self, Option<AppError>) {
+        match storage::load_json_or_set_aside(&path) {
+            Ok(data) => (Self::new(path, data), None),
+            Err(e) => (Self::new(path, HistoryData::default()), Some(e)),
+        }
+    }
+
+    /// Records fetched profiles; the file is written in the background
+    ///
+    /// Returns the error of a failed earlier write, if any.
+    pub fn record(&self, profiles: &[SteamProfile]) -> Result<(), AppError> {
+        if !profiles.is_empty() {
+            self.inner.data.lock().unwrap().record(profiles, Utc::now());
+
+            if !self.inner.save_pending.swap(true, Ordering::SeqCst) {
+                let inner = Arc::clone(&self.inner);
+                tauri::async_runtime::spawn(async move {
+                    tokio::time::sleep(SAVE_DELAY).await;
+                    inner.save_pending.store(false, Ordering::SeqCst);
+                    let _ = tauri::async_runtime::spawn_blocking(move || {
+                        if let Err(e) = inner.save() {
+                            *inner.write_error.lock().unwrap() = Some(e);
+                        }
+                    })
+                    .await;
+                });
+            }
+        }
+
+        match self.inner.write_error.lock().unwrap().take() {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    /// Writes a pending change right away (on exit)
+    pub fn flush(&self) -> Result<(), AppError> {
+        if self.inner.save_pending.swap(false, Ordering::SeqCst) {
+            self.inner.save()?;
+        }
+        Ok(())
+    }
+
+    /// Latest known state of the account
+    pub fn latest(&self, account_id: u32) -> Option<ProfileSnapshot> {
+        let data = self.inner.data.lock().unwrap();
+        data.accounts
+            .get(&account_id)
+            .and_then(|snapshots| snapshots.last())
+            .cloned()
+    }
+
+    /// Returns history of a single account
+    pub fn account(&self, account_id: u32) -> AccountHistory {
+        let data = self.inner.data.lock().unwrap();
+        let snapshots = data.accounts.get(&account_id).cloned().unwrap_or_default();
+
+        let mut changes = Vec::new();
+        for pair in snapshots.windows(2) {
+            let (old, new) = (&pair[0], &pair[1]);
+            let mut push = |field, old_value: Option<&String>, new_value: Option<&String>| {
+                if old_value != new_value {
+                    changes.push(ProfileChange {
+                        field,
+                        old_value: old_value.cloned(),
+                        new_value: new_value.cloned(),
+                        changed_at: new.first_seen,
+                    });
+                }
+            };
+            push(
+                ProfileField::Personaname,
+                Some(&old.personaname),
+                Some(&new.personaname),
+            );
+            push(
+                ProfileField::Avatar,
+                Some(&old.avatarfull),
+                Some(&new.avatarfull),
+            );
+            push(
+                ProfileField::Countrycode,
+                old.countrycode.as_ref(),
+                new.countrycode.as_ref(),
+            );
+        }
+
+        let mut known_names: Vec<String> = Vec::new();
+        for snapshot in snapshots.iter().rev() {
+            if !known_names.contains(&snapshot.personaname) {
+                known_names.push(snapshot.personaname.clone());
+            }
+        }
+
+        AccountHistory {
+            account_id,
+            snapshots,
+            changes,
+            known_names,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(account_id: u32, personaname: &str) -> SteamProfile {
+        SteamProfile {
+            account_id,
+            personaname: personaname.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn stores_snapshot_only_on_change() {
+        let mut data = HistoryData::default();
+        let now = Utc::now();
+        data.record(&[profile(1, "a")], now);
+        data.record(&[profile(1, "a")], now);
+        data.record(&[profile(1, "b")], now);
+
+        let names: Vec<&str> = data.accounts[&1]
+            .iter()
+            .map(|snapshot| snapshot.personaname.as_str())
+            .collect();
+        assert_eq!(names, ["a", "b"]);
+    }
+
+    #[test]
+    fn caps_snapshots_per_account() {
+        let mut data = HistoryData::default();
+        for i in 0..MAX_SNAPSHOTS + 5 {
+            data.record(&[profile(1, &i.to_string())], Utc::now());
+        }
+
+        let snapshots = &data.accounts[&1];
+        assert_eq!(snapshots.len(), MAX_SNAPSHOTS);
+        assert_eq!(snapshots[0].personaname, "5");
+    }
+
+    #[test]
+    fn drops_least_recently_seen_accounts() {
+        let mut data = HistoryData::default();
+        let start = Utc::now();
+        for id in 0..MAX_ACCOUNTS as u32 + 2 {
+            data.record(
+                &[profile(id, "name")],
+                start + chrono::Duration::seconds(i64::from(id)),
+            );
+        }
+
+        assert_eq!(data.accounts.len(), MAX_ACCOUNTS);
+        assert!(!data.accounts.contains_key(&0));
+        assert!(!data.accounts.contains_key(&1));
+        assert!(data.accounts.contains_key(&2));
+    }
+
+    #[test]
+    fn moves_corrupt_file_aside() {
+        let dir = std::env::temp_dir().join(format!("deadlyze-history-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(HISTORY_FILE);
+        std::fs::write(&path, "{ not json").unwrap();
+
+        let (history, error) = ProfileHistory::load(path.clone());
+
+        assert!(error.is_some());
+        assert!(history.latest(1).is_none());
+        assert!(!path.exists());
+        assert_eq!(
+            std::fs::read_to_string(path.with_extension("json.corrupt")).unwrap(),
+            "{ not json"
+        );
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
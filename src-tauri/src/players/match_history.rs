@@ -0,0 +1,292 @@
+//! Player match history (GET /v1/players/{account_id}/match-history)
+//!
+//! Matches are stored per account in the app data directory. Sync first checks
+//! the latest `match_id` from the MMR endpoint and downloads only matches newer
+//! than the last stored one when the account has played since.
+
+use std::cmp::Reverse;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::fetch_mmr;
+use crate::api::generated::GetMatchHistoryParams;
+use crate::api::ApiClient;
+use crate::error::AppError;
+use crate::storage;
+
+/// Directory with per-account history files (inside app data directory)
+pub const MATCH_HISTORY_DIR: &str = "match_history";
+
+/// Single match from player's perspective (model from the OpenAPI spec)
+pub use crate::api::generated::PlayerMatch;
+
+impl PlayerMatch {
+    pub fn won(&self) -> bool {
+        self.match_result == self.player_team
+    }
+}
+
+/// Match history entry as sent to the UI
+#[derive(Debug, Clone, Serialize)]
+pub struct MatchHistoryEntry {
+    #[serde(flatten)]
+    pub details: PlayerMatch,
+    pub won: bool,
+}
+
+/// Stored history of one account (newest match first)
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct StoredHistory {
+    last_synced: Option<DateTime<Utc>>,
+    matches: Vec<PlayerMatch>,
+}
+
+impl StoredHistory {
+    fn last_match_id(&self) -> Option<u64> {
+        self.matches.first().map(|m| m.match_id)
+    }
+
+    /// Adds matches newer than the last stored one; returns number of added matches
+    fn merge_newer(&mut self, fetched: Vec<PlayerMatch>) -> usize {
+        let last_match_id = self.last_match_id().unwrap_or(0);
+        let mut newer: Vec<PlayerMatch> = fetched
+            .into_iter()
+            .filter(|m| m.match_id > last_match_id)
+            .collect();
+        newer.sort_by_key(|m| Reverse(m.match_id));
+        newer.dedup_by_key(|m| m.match_id);
+
+        let added = newer.len();
+        newer.append(&mut self.matches);
+        self.matches = newer;
+        added
+    }
+}
+
+/// Result of a sync run
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncResult {
+    pub account_id: u32,
+    pub new_matches: usize,
+    pub total_matches: usize,
+    pub last_match_id: Option<u64>,
+}
+
+/// Match history store (managed as Tauri state)
+pub struct MatchHistoryStore {
+    dir: PathBuf,
+    /// Serializes sync runs and file access
+    lock: tokio::sync::Mutex<()>,
+}
+
+/// Runs file access on the blocking pool (history files can be large)
+async fn blocking<T: Send + 'static>(
+    path: &Path,
+    task: impl FnOnce() -> Result<T, AppError> + Send + 'static,
+) -> Result<T, AppError> {
+    tauri::async_runtime::spawn_blocking(task)
+        .await
+        .map_err(|e| AppError::storage(path, e))?
+}
+
+impl MatchHistoryStore {
+    pub fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    fn path(&self, account_id: u32) -> PathBuf {
+        self.dir.join(format!("{}.json", account_id))
+    }
+
+    async fn load(&self, account_id: u32) -> Result<StoredHistory, AppError> {
+        let path = self.path(account_id);
+        blocking(&path.clone(), move || storage::load_json(&path)).await
+    }
+
+    /// Stored matches of the account, newest first
+    pub async fn matches(&self, account_id: u32) -> Result<Vec<PlayerMatch>, AppError> {
+        let _guard = self.lock.lock().await;
+        Ok(self.load(account_id).await?.matches)
+    }
+
+    /// Latest stored match ID of the account
+    pub async fn last_match_id(&self, account_id: u32) -> Result<Option<u64>, AppError> {
+        let _guard = self.lock.lock().await;
+        Ok(self.load(account_id).await?.last_match_id())
+    }
+
+    /// Merges fetched matches into stored history
+    async fn merge(
+        &self,
+        account_id: u32,
+        fetched: Vec<PlayerMatch>,
+    ) -> Result<SyncResult, AppError> {
+        let _guard = self.lock.lock().await;
+        let mut history = self.load(account_id).await?;
+
+        let new_matches = history.merge_newer(fetched);
+        history.last_synced = Some(Utc::now());
+        let path = self.path(account_id);
+        let history = blocking(&path.clone(), move || {
+            storage::save_json(&path, &history)?;
+            Ok(history)
+        })
+        .await?;
+
+        Ok(SyncResult {
+            account_id,
+            new_matches,
+            total_matches: history.matches.len(),
+            last_match_id: history.last_match_id(),
+        })
+    }
+}
+
+/// Fetches match history of the account from the API (only matches after
+/// `after_match_id` when set)
+pub async fn fetch_match_history(
+    api: &ApiClient,
+    account_id: u32,
+    after_match_id: Option<u64>,
+) -> Result<Vec<PlayerMatch>, AppError> {
+    let params = GetMatchHistoryParams {
+        min_match_id: after_match_id.map(|id| id + 1),
+    };
+    api.get_match_history(account_id, &params).await
+}
+
+/// Downloads matches newer than the last stored `match_id`
+pub async fn sync(
+    api: &ApiClient,
+    store: &MatchHistoryStore,
+    account_id: u32,
+) -> Result<SyncResult, AppError> {
+    let last_match_id = store.last_match_id(account_id).await?;
+
+    // MMR carries the latest match: skip history download when nothing is new
+    if let Some(last_match_id) = last_match_id {
+        let mmr = fetch_mmr(api, &[account_id]).await?;
+        let up_to_date = mmr
+            .iter()
+            .find(|entry| entry.account_id == account_id)
+            .is_some_and(|entry| entry.match_id <= last_match_id);
+
+        if up_to_date {
+            return store.merge(account_id, Vec::new()).await;
+        }
+    }
+
+    let fetched = fetch_match_history(api, account_id, last_match_id).await?;
+    store.merge(account_id, fetched).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::api::test_server::{Reply, TestServer};
+
+    fn matches(ids: &[u64]) -> Vec<PlayerMatch> {
+        ids.iter()
+            .map(|&match_id| PlayerMatch {
+                match_id,
+                ..PlayerMatch::default()
+            })
+            .collect()
+    }
+
+    fn ids(matches: &[PlayerMatch]) -> Vec<u64> {
+        matches.iter().map(|m| m.match_id).collect()
+    }
+
+    #[test]
+    fn merges_only_newer_matches_newest_first() {
+        let mut history = StoredHistory {
+            last_synced: None,
+            matches: matches(&[5, 3]),
+        };
+
+        let added = history.merge_newer(matches(&[4, 7, 6, 7, 5, 2]));
+
+        assert_eq!(added, 2);
+        assert_eq!(ids(&history.matches), [7, 6, 5, 3]);
+        assert_eq!(history.last_match_id(), Some(7));
+    }
+
+    #[test]
+    fn merges_into_empty_history() {
+        let mut history = StoredHistory::default();
+
+        assert_eq!(history.merge_newer(matches(&[1, 3, 2])), 3);
+        assert_eq!(ids(&history.matches), [3, 2, 1]);
+        assert_eq!(history.merge_newer(Vec::new()), 0);
+    }
+
+    #[test]
+    fn syncs_only_when_the_account_played_since() {
+        let latest = Arc::new(AtomicU64::new(8));
+        let server = TestServer::start({
+            let latest = Arc::clone(&latest);
+            move |request| {
+                let latest = latest.load(Ordering::SeqCst);
+                if request.target.starts_with("/v1/players/mmr") {
+                    let mmr = [serde_json::json!({
+                        "account_id": 1,
+                        "division": 1,
+                        "division_tier": 1,
+                        "match_id": latest,
+                        "player_score": 1.0,
+                        "rank": 11,
+                        "start_time": 0,
+                    })];
+                    return Reply::json(serde_json::to_string(&mmr).unwrap());
+                }
+                let after: u64 = request
+                    .target
+                    .split("min_match_id=")
+                    .nth(1)
+                    .map_or(0, |id| id.parse().unwrap());
+                let history: Vec<u64> = (after.max(1)..=latest).rev().collect();
+                Reply::json(serde_json::to_string(&matches(&history)).unwrap())
+            }
+        });
+        let api = server.client();
+        let dir = std::env::temp_dir().join(format!("deadlyze-match-sync-{}", std::process::id()));
+        let store = MatchHistoryStore::new(dir.clone());
+
+        tauri::async_runtime::block_on(async {
+            // Nothing stored: full history without the MMR check
+            let result = sync(&api, &store, 1).await.unwrap();
+            assert_eq!((result.new_matches, result.total_matches), (8, 8));
+            assert_eq!(server.requests(), ["/v1/players/1/match-history"]);
+
+            // MMR reports the stored match: history isn't downloaded
+            let result = sync(&api, &store, 1).await.unwrap();
+            assert_eq!(result.new_matches, 0);
+            assert_eq!(server.requests().len(), 2);
+            assert!(server.requests()[1].starts_with("/v1/players/mmr"));
+
+            // Newer match: only matches after the stored one are requested
+            latest.store(10, Ordering::SeqCst);
+            let result = sync(&api, &store, 1).await.unwrap();
+            assert_eq!(result.new_matches, 2);
+            assert_eq!(result.last_match_id, Some(10));
+            assert_eq!(
+                server.requests().last().unwrap(),
+                "/v1/players/1/match-history?min_match_id=9"
+            );
+            assert_eq!(
+                ids(&store.matches(1).await.unwrap()),
+                [10, 9, 8, 7, 6, 5, 4, 3, 2, 1]
+            );
+        });
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,316 @@
+//! Teammate and opponent statistics ("played with" / "played against")
+//!
+//! Computed from stored match history joined with match metadata; metadata
+//! missing from the cache is downloaded, most recent matches first. Teammates
+//! from the same party are counted apart from random ones. When the API mate
+//! stats endpoint responds, its party totals take precedence since they cover
+//! matches that were never synced locally.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+use tokio::sync::Semaphore;
+
+use super::match_history::PlayerMatch;
+use super::MatchHistoryStore;
+use crate::api::generated::{GetMateStatsParams, PlayerMateStats};
+use crate::api::ApiClient;
+use crate::error::AppError;
+use crate::matches::{self, MatchCache, MatchMetadata};
+
+/// Notice source of failed metadata downloads
+const NOTICE: &str = "mates";
+
+/// Metadata downloads per request (older matches are filled in by later requests)
+const MAX_DOWNLOADS: usize = 100;
+
+/// Metadata downloads running at once
+const CONCURRENT_DOWNLOADS: usize = 6;
+
+/// Statistics with a single other player
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MateStats {
+    pub account_id: u32,
+    pub games_with: u64,
+    pub wins_with: u64,
+    pub win_rate_with: f64,
+    /// Games together in the same party (part of `games_with`)
+    pub games_in_party: u64,
+    pub wins_in_party: u64,
+    pub win_rate_in_party: f64,
+    pub games_against: u64,
+    pub wins_against: u64,
+    pub win_rate_against: f64,
+    /// Unix timestamp of the latest shared match (`None` when only known from the API)
+    pub last_played: Option<i64>,
+}
+
+fn rate(wins: u64, games: u64) -> f64 {
+    if games > 0 {
+        wins as f64 / games as f64
+    } else {
+        0.0
+    }
+}
+
+/// Metadata of the matches; missing entries are downloaded (bounded, concurrently)
+async fn load_metadata(app: &AppHandle, matches: &[PlayerMatch]) -> HashMap<u64, MatchMetadata> {
+    let cache = app.state::<MatchCache>();
+    let mut metadata = HashMap::new();
+    let mut missing = Vec::new();
+    for player_match in matches {
+        match cache.get(player_match.match_id).await {
+            Some(entry) => {
+                metadata.insert(player_match.match_id, entry);
+            }
+            None => missing.push(player_match.match_id),
+        }
+    }
+
+    let semaphore = Arc::new(Semaphore::new(CONCURRENT_DOWNLOADS));
+    let downloads: Vec<_> = missing
+        .into_iter()
+        .take(MAX_DOWNLOADS)
+        .map(|match_id| {
+            let app = app.clone();
+            let semaphore = Arc::clone(&semaphore);
+            tauri::async_runtime::spawn(async move {
+                let _permit = semaphore.acquire_owned().await;
+                let api = app.state::<ApiClient>();
+                let cache = app.state::<MatchCache>();
+                matches::load_match_metadata(&api, &cache, match_id).await
+            })
+        })
+        .collect();
+
+    // Stats cover whatever was loaded; the last failure is reported
+    let mut failure = None;
+    for download in downloads {
+        match download.await {
+            Ok(Ok(entry)) => {
+                metadata.insert(entry.match_info.match_id, entry);
+            }
+            Ok(Err(e)) => failure = Some(e),
+            Err(e) => failure = Some(AppError::network("match metadata", e)),
+        }
+    }
+    if let Some(e) = failure {
+        app.state::<ApiClient>().notices().record(NOTICE, e);
+    }
+
+    metadata
+}
+
+/// Counts teammates and opponents over matches with known metadata
+fn count_mates(
+    account_id: u32,
+    matches: &[PlayerMatch],
+    metadata: &HashMap<u64, MatchMetadata>,
+) -> HashMap<u32, MateStats> {
+    let mut mates: HashMap<u32, MateStats> = HashMap::new();
+
+    for player_match in matches {
+        let Some(metadata) = metadata.get(&player_match.match_id) else {
+            continue;
+        };
+        let players = &metadata.match_info.players;
+        let won = player_match.won();
+        let party = players
+            .iter()
+            .find(|player| player.account_id == account_id)
+            .map_or(0, |player| player.party);
+
+        for other in players {
+            if other.account_id == account_id || other.account_id == 0 {
+                continue;
+            }
+
+            let stats = mates.entry(other.account_id).or_insert_with(|| MateStats {
+                account_id: other.account_id,
+                ..MateStats::default()
+            });
+
+            if other.team == player_match.player_team {
+                stats.games_with += 1;
+                stats.wins_with += u64::from(won);
+                if party != 0 && other.party == party {
+                    stats.games_in_party += 1;
+                    stats.wins_in_party += u64::from(won);
+                }
+            } else {
+                stats.games_against += 1;
+                stats.wins_against += u64::from(won);
+            }
+            stats.last_played = stats.last_played.max(Some(player_match.start_time));
+        }
+    }
+
+    mates
+}
+
+/// Computes mate stats from locally stored matches
+pub async fn local_mates(
+    app: &AppHandle,
+    store: &MatchHistoryStore,
+    account_id: u32,
+) -> Result<HashMap<u32, MateStats>, AppError> {
+    let matches = store.matches(account_id).await?;
+    let metadata = load_metadata(app, &matches).await;
+    Ok(count_mates(account_id, &matches, &metadata))
+}
+
+/// Fetches stats of teammates from the same party from the API
+/// (GET /v1/players/{account_id}/mate-stats)
+pub async fn fetch_mate_stats(
+    api: &ApiClient,
+    account_id: u32,
+) -> Result<Vec<PlayerMateStats>, AppError> {
+    let params = GetMateStatsParams {
+        same_party: Some(true),
+    };
+    api.get_mate_stats(account_id, &params).await
+}
+
+/// Merges API party totals into local stats
+///
+/// Party games missing locally count as games together as well.
+pub fn merge_api_stats(mates: &mut HashMap<u32, MateStats>, api_stats: Vec<PlayerMateStats>) {
+    for entry in api_stats {
+        let stats = mates.entry(entry.mate_id).or_insert_with(|| MateStats {
+            account_id: entry.mate_id,
+            ..MateStats::default()
+        });
+        if entry.matches_played >= stats.games_in_party {
+            stats.games_with += entry.matches_played - stats.games_in_party;
+            stats.wins_with += entry.wins.saturating_sub(stats.wins_in_party);
+            stats.games_in_party = entry.matches_played;
+            stats.wins_in_party = entry.wins;
+        }
+    }
+}
+
+/// Finalizes rates and sorts by games together (then by win rate together)
+pub fn finalize(mates: HashMap<u32, MateStats>, min_games: u64) -> Vec<MateStats> {
+    let mut mates: Vec<MateStats> = mates
+        .into_values()
+        .filter(|stats| stats.games_with + stats.games_against >= min_games)
+        .map(|mut stats| {
+            stats.win_rate_with = rate(stats.wins_with, stats.games_with);
+            stats.win_rate_in_party = rate(stats.wins_in_party, stats.games_in_party);
+            stats.win_rate_against = rate(stats.wins_against, stats.games_against);
+            stats
+        })
+        .collect();
+
+    mates.sort_by(|a, b| {
+        b.games_with
+            .cmp(&a.games_with)
+            .then(b.win_rate_with.total_cmp(&a.win_rate_with))
+            .then(b.games_against.cmp(&a.games_against))
+    });
+    mates
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn player(account_id: u32, team: u32, party: u64) -> serde_json::Value {
+        json!({
+            "account_id": account_id,
+            "player_slot": 0,
+            "team": team,
+            "hero_id": 1,
+            "party": party,
+            "kills": 0,
+            "deaths": 0,
+            "assists": 0,
+            "net_worth": 0,
+        })
+    }
+
+    fn played(match_id: u64, players: Vec<serde_json::Value>) -> (PlayerMatch, MatchMetadata) {
+        let player_match = serde_json::from_value(json!({
+            "match_id": match_id,
+            "hero_id": 1,
+            "start_time": match_id,
+            "match_duration_s": 1800,
+            "player_team": 0,
+            "match_result": 0,
+            "player_kills": 0,
+            "player_deaths": 0,
+            "player_assists": 0,
+            "net_worth": 0,
+        }))
+        .unwrap();
+        let metadata = serde_json::from_value(json!({
+            "match_info": {
+                "match_id": match_id,
+                "start_time": match_id,
+                "duration_s": 1800,
+                "winning_team": 0,
+                "players": players,
+            }
+        }))
+        .unwrap();
+        (player_match, metadata)
+    }
+
+    #[test]
+    fn separates_party_from_random_teammates() {
+        let (matches, metadata): (Vec<_>, Vec<_>) = [
+            played(1, vec![player(7, 0, 5), player(8, 0, 5), player(9, 0, 0)]),
+            played(2, vec![player(7, 0, 0), player(8, 0, 0), player(9, 1, 0)]),
+        ]
+        .into_iter()
+        .unzip();
+        let metadata = metadata
+            .into_iter()
+            .map(|entry: MatchMetadata| (entry.match_info.match_id, entry))
+            .collect();
+
+        let mates = count_mates(7, &matches, &metadata);
+
+        assert!(!mates.contains_key(&7));
+        assert_eq!(mates[&8].games_with, 2);
+        assert_eq!(mates[&8].games_in_party, 1);
+        assert_eq!(mates[&9].games_with, 1);
+        assert_eq!(mates[&9].games_in_party, 0);
+        assert_eq!(mates[&9].games_against, 1);
+        assert_eq!(mates[&9].last_played, Some(2));
+    }
+
+    #[test]
+    fn api_party_totals_extend_local_stats() {
+        let mut mates = HashMap::from([(
+            8,
+            MateStats {
+                account_id: 8,
+                games_with: 5,
+                wins_with: 3,
+                games_in_party: 2,
+                wins_in_party: 1,
+                ..MateStats::default()
+            },
+        )]);
+
+        merge_api_stats(
+            &mut mates,
+            vec![PlayerMateStats {
+                mate_id: 8,
+                wins: 4,
+                matches_played: 6,
+                matches: Vec::new(),
+            }],
+        );
+
+        assert_eq!(mates[&8].games_in_party, 6);
+        assert_eq!(mates[&8].wins_in_party, 4);
+        assert_eq!(mates[&8].games_with, 9);
+        assert_eq!(mates[&8].wins_with, 6);
+    }
+}
@@ -0,0 +1,126 @@
+//! Player data (Steam profiles, MMR, match history)
+
+mod history;
+mod match_history;
+mod mates;
+mod search;
+
+pub use history::{AccountHistory, ProfileHistory, HISTORY_FILE, HISTORY_NOTICE};
+pub use match_history::{MatchHistoryEntry, MatchHistoryStore, SyncResult, MATCH_HISTORY_DIR};
+pub use search::{PlayerCandidate, PlayerSearch};
+
+/// Steam profile and player rank models come from the OpenAPI spec
+pub use crate::api::generated::{PlayerMMR, SteamProfile};
+
+use tauri::{AppHandle, State};
+
+use crate::api::generated::{GetMmrParams, GetSteamProfilesParams};
+use crate::api::ApiClient;
+use crate::error::AppError;
+
+/// Fetches Steam profiles for multiple accounts
+pub async fn fetch_steam_profiles(
+    api: &ApiClient,
+    account_ids: &[u32],
+) -> Result<Vec<SteamProfile>, AppError> {
+    if account_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    api.get_steam_profiles(&GetSteamProfilesParams {
+        account_ids: account_ids.to_vec(),
+    })
+    .await
+}
+
+/// Fetches MMR for multiple accounts
+pub async fn fetch_mmr(api: &ApiClient, account_ids: &[u32]) -> Result<Vec<PlayerMMR>, AppError> {
+    if account_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    api.get_mmr(&GetMmrParams {
+        account_ids: account_ids.to_vec(),
+    })
+    .await
+}
+
+/// Returns Steam profiles for the given accounts (recorded into profile history)
+#[tauri::command]
+pub async fn get_steam_profiles(
+    api: State<'_, ApiClient>,
+    history: State<'_, ProfileHistory>,
+    account_ids: Vec<u32>,
+) -> Result<Vec<SteamProfile>, AppError> {
+    let profiles = fetch_steam_profiles(&api, &account_ids).await?;
+    if let Err(e) = history.record(&profiles) {
+        api.notices().record(HISTORY_NOTICE, e);
+    }
+    Ok(profiles)
+}
+
+/// Returns stored persona name, avatar and country code changes of the account
+#[tauri::command]
+pub fn get_profile_history(history: State<'_, ProfileHistory>, account_id: u32) -> AccountHistory {
+    history.account(account_id)
+}
+
+/// Downloads new matches of the account into local match history
+#[tauri::command]
+pub async fn sync_match_history(
+    api: State<'_, ApiClient>,
+    store: State<'_, MatchHistoryStore>,
+    account_id: u32,
+) -> Result<SyncResult, AppError> {
+    match_history::sync(&api, &store, account_id).await
+}
+
+/// Returns stored match history of the account, newest first
+#[tauri::command]
+pub async fn get_match_history(
+    store: State<'_, MatchHistoryStore>,
+    account_id: u32,
+    limit: Option<usize>,
+) -> Result<Vec<MatchHistoryEntry>, AppError> {
+    let matches = store.matches(account_id).await?;
+    Ok(matches
+        .into_iter()
+        .take(limit.unwrap_or(usize::MAX))
+        .map(|details| MatchHistoryEntry {
+            won: details.won(),
+            details,
+        })
+        .collect())
+}
+
+/// Searches players by Steam name; `None` means the search was superseded by a newer one
+#[tauri::command]
+pub async fn search_players(
+    api: State<'_, ApiClient>,
+    state: State<'_, PlayerSearch>,
+    history: State<'_, ProfileHistory>,
+    query: String,
+) -> Result<Option<Vec<PlayerCandidate>>, AppError> {
+    search::search(&api, &state, &history, &query).await
+}
+
+/// Returns players the account most often plays with and against
+///
+/// Local stats cover synced matches (missing match metadata is downloaded);
+/// API party stats are merged in when the endpoint is available.
+#[tauri::command]
+pub async fn get_mates(
+    app: AppHandle,
+    api: State<'_, ApiClient>,
+    store: State<'_, MatchHistoryStore>,
+    account_id: u32,
+    min_games: Option<u64>,
+) -> Result<Vec<mates::MateStats>, AppError> {
+    let mut stats = mates::local_mates(&app, &store, account_id).await?;
+
+    if let Ok(api_stats) = mates::fetch_mate_stats(&api, account_id).await {
+        mates::merge_api_stats(&mut stats, api_stats);
+    }
+
+    Ok(mates::finalize(stats, min_games.unwrap_or(1)))
+}
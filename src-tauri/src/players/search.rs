@@ -0,0 +1,219 @@
+//! Player search by Steam name (GET /v1/players/steam-search)
+//!
+//! Built for type-ahead input: requests are debounced on the backend (a newer
+//! search supersedes the pending one) and results are cached per query.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use super::{fetch_mmr, PlayerMMR, ProfileHistory, SteamProfile, HISTORY_NOTICE};
+use crate::api::generated::SearchSteamProfilesParams;
+use crate::api::ApiClient;
+use crate::avatars::{self, AvatarSize};
+use crate::error::AppError;
+
+/// Queries shorter than this return no candidates
+const MIN_QUERY_LEN: usize = 2;
+
+/// Delay before hitting the network; newer keystrokes cancel the request
+const DEBOUNCE: Duration = Duration::from_millis(250);
+
+const CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+const CACHE_CAPACITY: usize = 64;
+const MAX_CANDIDATES: usize = 20;
+
+/// Search candidate as sent to the UI
+#[derive(Debug, Clone, Serialize)]
+pub struct PlayerCandidate {
+    pub account_id: u32,
+    pub personaname: String,
+    pub avatar: String,
+    pub profileurl: String,
+    pub countrycode: Option<String>,
+    /// Current rank (`None` for players without ranked matches)
+    pub mmr: Option<PlayerMMR>,
+}
+
+/// Search state (managed as Tauri state)
+#[derive(Default)]
+pub struct PlayerSearch {
+    generation: AtomicU64,
+    /// Most recently used last
+    cache: Mutex<VecDeque<(String, Instant, Vec<PlayerCandidate>)>>,
+}
+
+impl PlayerSearch {
+    fn cached(&self, query: &str) -> Option<Vec<PlayerCandidate>> {
+        let mut cache = self.cache.lock().unwrap();
+        cache.retain(|(_, stored_at, _)| stored_at.elapsed() < CACHE_TTL);
+
+        let index = cache.iter().position(|(key, _, _)| key == query)?;
+        let entry = cache.remove(index)?;
+        let candidates = entry.2.clone();
+        cache.push_back(entry);
+        Some(candidates)
+    }
+
+    fn store(&self, query: String, candidates: Vec<PlayerCandidate>) {
+        let mut cache = self.cache.lock().unwrap();
+        cache.retain(|(key, _, _)| *key != query);
+        if cache.len() >= CACHE_CAPACITY {
+            cache.pop_front();
+        }
+        cache.push_back((query, Instant::now(), candidates));
+    }
+}
+
+/// Normalizes query for caching (case and surrounding whitespace are ignored)
+fn normalize(query: &str) -> String {
+    query.trim().to_lowercase()
+}
+
+async fn fetch_candidates(api: &ApiClient, query: &str) -> Result<Vec<SteamProfile>, AppError> {
+    api.search_steam_profiles(&SearchSteamProfilesParams {
+        search_query: query.to_string(),
+    })
+    .await
+}
+
+/// Searches players; returns `None` when the search was superseded by a newer one
+pub async fn search(
+    api: &ApiClient,
+    state: &PlayerSearch,
+    history: &ProfileHistory,
+    query: &str,
+) -> Result<Option<Vec<PlayerCandidate>>, AppError> {
+    let query = normalize(query);
+    let generation = state.generation.fetch_add(1, Ordering::SeqCst) + 1;
+
+    if query.chars().count() < MIN_QUERY_LEN {
+        return Ok(Some(Vec::new()));
+    }
+    if let Some(candidates) = state.cached(&query) {
+        return Ok(Some(candidates));
+    }
+
+    tokio::time::sleep(DEBOUNCE).await;
+    if state.generation.load(Ordering::SeqCst) != generation {
+        return Ok(None);
+    }
+
+    let mut profiles = fetch_candidates(api, &query).await?;
+    profiles.truncate(MAX_CANDIDATES);
+    // History is a side record: a failed write must not fail the search
+    if let Err(e) = history.record(&profiles) {
+        api.notices().record(HISTORY_NOTICE, e);
+    }
+
+    let account_ids: Vec<u32> = profiles.iter().map(|p| p.account_id).collect();
+    // Rank is optional decoration: search still works when MMR lookup fails
+    let ranks = fetch_mmr(api, &account_ids).await.unwrap_or_default();
+
+    let candidates: Vec<PlayerCandidate> = profiles
+        .iter()
+        .map(|profile| PlayerCandidate {
+            account_id: profile.account_id,
+            personaname: profile.personaname.clone(),
+            avatar: avatars::avatar_url(profile.account_id, AvatarSize::Medium),
+            profileurl: profile.profileurl.clone(),
+            countrycode: profile.countrycode.clone(),
+            mmr: ranks
+                .iter()
+                .find(|mmr| mmr.account_id == profile.account_id)
+                .cloned(),
+        })
+        .collect();
+
+    state.store(query, candidates.clone());
+    Ok(Some(candidates))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::test_server::{Reply, TestServer};
+
+    fn candidate(account_id: u32) -> PlayerCandidate {
+        PlayerCandidate {
+            account_id,
+            personaname: format!("player {}", account_id),
+            avatar: String::new(),
+            profileurl: String::new(),
+            countrycode: None,
+            mmr: None,
+        }
+    }
+
+    fn ids(candidates: &[PlayerCandidate]) -> Vec<u32> {
+        candidates.iter().map(|c| c.account_id).collect()
+    }
+
+    #[test]
+    fn newer_search_supersedes_pending_one() {
+        let server = TestServer::start(|request| {
+            if !request.target.starts_with("/v1/players/steam-search") {
+                return Reply::json("[]");
+            }
+            let profiles = [SteamProfile {
+                account_id: 7,
+                personaname: "Seven".to_string(),
+                ..SteamProfile::default()
+            }];
+            Reply::json(serde_json::to_string(&profiles).unwrap())
+        });
+        let api = server.client();
+        let state = PlayerSearch::default();
+        let dir = std::env::temp_dir().join(format!("deadlyze-search-{}", std::process::id()));
+        let (history, _) = ProfileHistory::load(dir.join("history.json"));
+
+        let (first, second) = tauri::async_runtime::block_on(async {
+            tokio::join!(search(&api, &state, &history, "sev"), async {
+                tokio::time::sleep(DEBOUNCE / 5).await;
+                search(&api, &state, &history, " Seven ").await
+            })
+        });
+
+        assert!(first.unwrap().is_none());
+        let candidates = second.unwrap().unwrap();
+        assert_eq!(ids(&candidates), [7]);
+        assert_eq!(
+            candidates[0].avatar,
+            avatars::avatar_url(7, AvatarSize::Medium)
+        );
+        let searches: Vec<String> = server
+            .requests()
+            .into_iter()
+            .filter(|target| target.starts_with("/v1/players/steam-search"))
+            .collect();
+        assert_eq!(searches, ["/v1/players/steam-search?search_query=seven"]);
+
+        // Repeated query is answered from the cache
+        let cached = tauri::async_runtime::block_on(search(&api, &state, &history, "SEVEN"));
+        assert_eq!(ids(&cached.unwrap().unwrap()), [7]);
+        assert_eq!(server.requests().len(), 2);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn drops_expired_and_least_recently_used_results() {
+        let state = PlayerSearch::default();
+        state.store("old".to_string(), vec![candidate(1)]);
+        state.cache.lock().unwrap()[0].1 = Instant::now() - CACHE_TTL;
+        assert!(state.cached("old").is_none());
+
+        for i in 0..CACHE_CAPACITY as u32 {
+            state.store(i.to_string(), vec![candidate(i)]);
+        }
+        // Reading marks the entry as recently used, so the next one goes
+        assert_eq!(ids(&state.cached("0").unwrap()), [0]);
+        state.store("new".to_string(), vec![candidate(100)]);
+
+        assert!(state.cached("0").is_some());
+        assert!(state.cached("1").is_none());
+        assert_eq!(ids(&state.cached("new").unwrap()), [100]);
+    }
+}
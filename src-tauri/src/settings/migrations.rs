@@ -0,0 +1,100 @@
+//! Settings schema migrations
+//!
+//! Each migration upgrades the raw JSON by one version. Files without
+//! `version` are version 0 (written by the frontend before settings moved to
+//! the backend).
+
+use serde_json::{Map, Value};
+
+use super::{Settings, OPACITY_MAX, OPACITY_MIN, SCHEMA_VERSION};
+use crate::error::AppError;
+
+type Migration = fn(&mut Map<String, Value>);
+
+/// Migration from version `i` to `i + 1` at index `i`
+const MIGRATIONS: &[Migration] = &[v0_to_v1];
+
+const _: () = assert!(MIGRATIONS.len() == SCHEMA_VERSION as usize);
+
+/// v0 -> v1: opacity becomes an integer percent within the allowed range
+fn v0_to_v1(settings: &mut Map<String, Value>) {
+    if let Some(opacity) = settings.get("opacity").and_then(Value::as_f64) {
+        let opacity = opacity
+            .round()
+            .clamp(f64::from(OPACITY_MIN), f64::from(OPACITY_MAX));
+        settings.insert("opacity".into(), Value::from(opacity as u32));
+    }
+}
+
+/// Schema version of raw settings JSON
+pub fn version(settings: &Map<String, Value>) -> Result<u32, AppError> {
+    match settings.get("version") {
+        None => Ok(0),
+        Some(version) => version
+            .as_u64()
+            .and_then(|version| u32::try_from(version).ok())
+            .ok_or_else(|| AppError::invalid_settings("version", "expected a number")),
+    }
+}
+
+/// Upgrades raw settings JSON to the current schema and decodes it
+pub fn migrate(value: Value) -> Result<Settings, AppError> {
+    let Value::Object(mut settings) = value else {
+        return Err(AppError::invalid_settings("settings", "expected an object"));
+    };
+
+    let version = version(&settings)?;
+    if version > SCHEMA_VERSION {
+        return Err(AppError::invalid_settings(
+            "version",
+            format!(
+                "version {} is newer than supported version {}",
+                version, SCHEMA_VERSION
+            ),
+        ));
+    }
+
+    for migration in &MIGRATIONS[version as usize..] {
+        migration(&mut settings);
+    }
+    settings.insert("version".into(), Value::from(SCHEMA_VERSION));
+
+    serde_path_to_error::deserialize(Value::Object(settings))
+        .map_err(|e| AppError::invalid_settings(&e.path().to_string(), e.inner()))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn migrates_frontend_file_without_version() {
+        let settings = migrate(json!({
+            "language": "en",
+            "opacity": 12.4,
+            "shortcut": "Alt+Q",
+        }))
+        .unwrap();
+
+        assert_eq!(settings.version, SCHEMA_VERSION);
+        assert_eq!(settings.language, "en");
+        assert_eq!(settings.opacity, OPACITY_MIN);
+        assert_eq!(settings.shortcut, "Alt+Q");
+    }
+
+    #[test]
+    fn rejects_newer_version() {
+        let error = migrate(json!({ "version": SCHEMA_VERSION + 1 })).unwrap_err();
+
+        assert_eq!(error.code(), "INVALID_SETTINGS");
+    }
+
+    #[test]
+    fn reports_mistyped_field() {
+        let error = migrate(json!({ "version": 1, "opacity": "high" })).unwrap_err();
+
+        assert!(error.to_string().contains("opacity"), "{}", error);
+    }
+}
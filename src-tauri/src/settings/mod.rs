@@ -0,0 +1,661 @@
+//! Application settings (language, window opacity, shortcut, game launch
+//! options, API connection)
+//!
+//! Stored as `config.json` in the app data directory with a schema version;
+//! older files are migrated on load. Updates are validated and their side
+//! effects (opacity, shortcut, API client) are applied by the backend, the
+//! frontend only renders the values. External edits of the file are picked
+//! up by [`watcher`]; the last known-good versions are kept as backups.
+
+pub mod migrations;
+pub mod profiles;
+pub mod watcher;
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::{AppHandle, Manager, State};
+use tauri_plugin_global_shortcut::Shortcut;
+
+use crate::api::config::ApiSettings;
+use crate::api::ApiClient;
+use crate::error::AppError;
+use crate::events::Event;
+use crate::storage;
+
+/// Settings file in the app data directory
+pub const SETTINGS_FILE: &str = "config.json";
+
+/// Current settings schema version
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Interface languages (`src/i18n/locales`)
+pub const LANGUAGES: &[&str] = &["ru", "en"];
+
+pub const OPACITY_MIN: u32 = 20;
+pub const OPACITY_MAX: u32 = 100;
+
+pub const LAUNCH_OPTIONS_MAX_LEN: usize = 256;
+
+/// Known-good copies kept as `config.json.bak1` (newest) .. `.bak3`
+const BACKUP_COUNT: usize = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Schema version of the stored file
+    pub version: u32,
+    pub language: String,
+    /// Window opacity in percent (20-100)
+    pub opacity: u32,
+    /// Show/hide window shortcut (e.g. "Alt+`")
+    pub shortcut: String,
+    /// Steam launch options of the game (e.g. "-novid +fps_max 240")
+    pub launch_options: String,
+    pub api: ApiSettings,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            version: SCHEMA_VERSION,
+            language: "ru".into(),
+            opacity: OPACITY_MAX,
+            shortcut: "Alt+`".into(),
+            launch_options: String::new(),
+            api: ApiSettings::default(),
+        }
+    }
+}
+
+impl Settings {
+    pub fn validate(&self) -> Result<(), AppError> {
+        if !LANGUAGES.contains(&self.language.as_str()) {
+            return Err(AppError::invalid_settings(
+                "language",
+                format!("unsupported language {}", self.language),
+            ));
+        }
+        if !(OPACITY_MIN..=OPACITY_MAX).contains(&self.opacity) {
+            return Err(AppError::invalid_settings(
+                "opacity",
+                format!(
+                    "expected {}-{}, got {}",
+                    OPACITY_MIN, OPACITY_MAX, self.opacity
+                ),
+            ));
+        }
+        if self.shortcut.parse::<Shortcut>().is_err() {
+            return Err(AppError::InvalidShortcut {
+                shortcut: self.shortcut.clone(),
+            });
+        }
+        validate_launch_options(&self.launch_options)?;
+        self.api.base_url()?;
+        Ok(())
+    }
+}
+
+/// Launch options are passed in a `steam://` URL, so characters with a
+/// meaning in URLs or in the shell are rejected
+fn validate_launch_options(options: &str) -> Result<(), AppError> {
+    let allowed = |c: char| c.is_ascii_alphanumeric() || " -+_.=:,".contains(c);
+    if options.len() > LAUNCH_OPTIONS_MAX_LEN {
+        return Err(AppError::invalid_settings(
+            "launch_options",
+            format!("expected up to {} characters", LAUNCH_OPTIONS_MAX_LEN),
+        ));
+    }
+    if let Some(c) = options.chars().find(|&c| !allowed(c)) {
+        return Err(AppError::invalid_settings(
+            "launch_options",
+            format!("unsupported character {:?}", c),
+        ));
+    }
+    Ok(())
+}
+
+/// Steam URL launching Deadlock (App ID 1422450) with the launch options
+///
+/// Options are percent-encoded, since Steam decodes the URL before passing
+/// them to the game.
+pub fn steam_launch_url(launch_options: &str) -> String {
+    let launch_options = launch_options.trim();
+    if launch_options.is_empty() {
+        return "steam://rungameid/1422450".to_string();
+    }
+
+    let encoded: String = launch_options
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                char::from(byte).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect();
+    format!("steam://run/1422450//{}/", encoded)
+}
+
+/// Partial update from the UI; missing fields keep their values
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SettingsPatch {
+    pub language: Option<String>,
+    pub opacity: Option<u32>,
+    pub shortcut: Option<String>,
+    pub launch_options: Option<String>,
+    pub api: Option<ApiSettings>,
+}
+
+impl SettingsPatch {
+    fn apply(self, settings: &Settings) -> Settings {
+        Settings {
+            version: SCHEMA_VERSION,
+            language: self.language.unwrap_or_else(|| settings.language.clone()),
+            opacity: self.opacity.unwrap_or(settings.opacity),
+            shortcut: self.shortcut.unwrap_or_else(|| settings.shortcut.clone()),
+            launch_options: self
+                .launch_options
+                .unwrap_or_else(|| settings.launch_options.clone()),
+            api: self.api.unwrap_or_else(|| settings.api.clone()),
+        }
+    }
+}
+
+/// Settings store (managed as Tauri state)
+pub struct SettingsStore {
+    path: PathBuf,
+    state: Mutex<StoreState>,
+}
+
+struct StoreState {
+    settings: Settings,
+    /// Why the file on disk was rejected or not applied (kept until the next save)
+    error: Option<AppError>,
+    /// `error` is a parse or validation failure of the file content (the file
+    /// is moved aside on the next save instead of becoming a backup)
+    invalid_file: bool,
+}
+
+/// Failure of the file content rather than of reading it
+fn is_invalid_file(error: &AppError) -> bool {
+    !matches!(error, AppError::Storage { .. })
+}
+
+impl SettingsStore {
+    /// Loads and migrates the settings file
+    ///
+    /// An invalid file is left on disk and reported; the newest valid backup
+    /// (or defaults) is used instead.
+    pub fn load(path: PathBuf) -> Self {
+        let (settings, error) = match Self::read(&path) {
+            Ok(Some((settings, migrated))) => {
+                if migrated {
+                    rotate_backups(&path);
+                    if let Err(e) = storage::save_json(&path, &settings) {
+                        eprintln!("Failed to save migrated settings: {}", e);
+                    }
+                }
+                (settings, None)
+            }
+            Ok(None) => (Settings::default(), None),
+            Err(e) => {
+                eprintln!("{}", e);
+                let settings = restore_backup(&path).unwrap_or_else(|| {
+                    eprintln!("No valid settings backup; using default settings");
+                    Settings::default()
+                });
+                (settings, Some(e))
+            }
+        };
+
+        let invalid_file = error.as_ref().is_some_and(is_invalid_file);
+        Self {
+            path,
+            state: Mutex::new(StoreState {
+                settings,
+                error,
+                invalid_file,
+            }),
+        }
+    }
+
+    /// Reads settings; the flag is set when the file was migrated
+    fn read(path: &Path) -> Result<Option<(Settings, bool)>, AppError> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => parse(path, &content).map(Some),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(AppError::storage(path, e)),
+        }
+    }
+
+    pub fn get(&self) -> Settings {
+        self.state.lock().unwrap().settings.clone()
+    }
+
+    /// Problem with the settings file on disk, if it was rejected
+    pub fn error(&self) -> Option<AppError> {
+        self.state.lock().unwrap().error.clone()
+    }
+
+    /// Validates and persists settings
+    ///
+    /// The previous file is rotated into the backups; a file that failed to
+    /// parse or validate is moved aside to `config.json.corrupt` instead.
+    /// Unchanged settings aren't written again.
+    pub fn save(&self, settings: Settings) -> Result<(), AppError> {
+        settings.validate()?;
+        let mut state = self.state.lock().unwrap();
+
+        if state.error.is_none() && state.settings == settings && self.path.exists() {
+            return Ok(());
+        }
+
+        if state.invalid_file {
+            let corrupt_path = self.path.with_extension("json.corrupt");
+            if let Err(e) = std::fs::copy(&self.path, &corrupt_path) {
+                if e.kind() != std::io::ErrorKind::NotFound {
+                    return Err(AppError::storage(&corrupt_path, e));
+                }
+            }
+        } else {
+            rotate_backups(&self.path);
+        }
+
+        storage::save_json(&self.path, &settings)?;
+        state.settings = settings;
+        state.error = None;
+        state.invalid_file = false;
+        Ok(())
+    }
+
+    /// Rereads the file after an external change
+    ///
+    /// `apply` runs the side effects of the new settings; returns `None` when
+    /// the file matches the current settings (e.g. after our own save). On
+    /// error the current settings stay in place.
+    fn reload(
+        &self,
+        apply: impl FnOnce(&Settings, &Settings) -> Result<(), AppError>,
+    ) -> Result<Option<Settings>, AppError> {
+        // Side effects run unlocked: the shortcut handler reads the store
+        // while holding the window state
+        let result = Self::read(&self.path)
+            .map_err(|e| {
+                let invalid_file = is_invalid_file(&e);
+                (e, invalid_file)
+            })
+            .and_then(|settings| {
+                // A removed file is recreated on the next save
+                let Some((settings, _)) = settings else {
+                    return Ok(None);
+                };
+                let old = self.get();
+                if settings == old {
+                    return Ok(None);
+                }
+                apply(&old, &settings).map_err(|e| (e, false))?;
+                Ok(Some(settings))
+            });
+
+        let mut state = self.state.lock().unwrap();
+        match result {
+            Ok(settings) => {
+                state.error = None;
+                state.invalid_file = false;
+                if let Some(settings) = &settings {
+                    state.settings = settings.clone();
+                }
+                Ok(settings)
+            }
+            Err((e, invalid_file)) => {
+                state.error = Some(e.clone());
+                state.invalid_file = invalid_file;
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Parses settings file content, migrating older schema versions
+///
+/// Syntax errors (and type errors in current-version files) keep their line
+/// and column.
+pub fn parse(path: &Path, content: &str) -> Result<(Settings, bool), AppError> {
+    let value: Value = decode(path, content)?;
+    let version = value.as_object().map(migrations::version).transpose()?;
+
+    let settings: Settings = if version == Some(SCHEMA_VERSION) {
+        decode(path, content)?
+    } else {
+        migrations::migrate(value)?
+    };
+
+    settings.validate()?;
+    Ok((settings, version != Some(SCHEMA_VERSION)))
+}
+
+/// Decodes JSON text; errors keep their position and, for mistyped values,
+/// the field path
+fn decode<T: DeserializeOwned>(path: &Path, content: &str) -> Result<T, AppError> {
+    let mut deserializer = serde_json::Deserializer::from_str(content);
+    serde_path_to_error::deserialize(&mut deserializer).map_err(|e| {
+        let field = e.path().to_string();
+        let field = e.inner().is_data().then_some(field.as_str());
+        AppError::settings_file(path, field, e.inner())
+    })
+}
+
+fn backup_path(path: &Path, index: usize) -> PathBuf {
+    path.with_extension(format!("json.bak{}", index))
+}
+
+/// Shifts `config.json.bak1..` by one and copies the current file into `.bak1`
+///
+/// Nothing is rotated when `.bak1` already has the current content.
+fn rotate_backups(path: &Path) {
+    let Ok(content) = std::fs::read(path) else {
+        return;
+    };
+    if std::fs::read(backup_path(path, 1)).is_ok_and(|backup| backup == content) {
+        return;
+    }
+    for index in (1..BACKUP_COUNT).rev() {
+        let _ = std::fs::rename(backup_path(path, index), backup_path(path, index + 1));
+    }
+    if let Err(e) = std::fs::write(backup_path(path, 1), content) {
+        eprintln!("Failed to back up settings: {}", e);
+    }
+}
+
+/// Newest backup that is still valid
+fn restore_backup(path: &Path) -> Option<Settings> {
+    (1..=BACKUP_COUNT).find_map(|index| {
+        let backup = backup_path(path, index);
+        match SettingsStore::read(&backup) {
+            Ok(Some((settings, _))) => {
+                eprintln!("Restored settings from {}", backup.display());
+                Some(settings)
+            }
+            _ => None,
+        }
+    })
+}
+
+/// Settings file changed on disk and was applied
+impl Event for Settings {
+    const NAME: &'static str = "settings-changed";
+}
+
+/// Settings file changed on disk but was rejected
+#[derive(Debug, Clone, Serialize)]
+pub struct SettingsFileError {
+    pub error: AppError,
+}
+
+impl Event for SettingsFileError {
+    const NAME: &'static str = "settings-file-error";
+}
+
+/// Applies opacity unless the window is hidden by the shortcut (restored on show)
+fn apply_opacity(app: &AppHandle, opacity: u32) -> Result<(), AppError> {
+    if *app.state::<crate::AppState>().is_hidden.lock().unwrap() {
+        return Ok(());
+    }
+    crate::set_window_opacity(app.clone(), f64::from(opacity))
+}
+
+/// Applies side effects of changed settings (API client, shortcut, opacity)
+///
+/// Shortcut and API changes are rolled back when a later step fails.
+fn apply_effects(
+    app: &AppHandle,
+    api: &ApiClient,
+    old: &Settings,
+    settings: &Settings,
+) -> Result<(), AppError> {
+    if settings.api != old.api {
+        api.configure(&settings.api)?;
+    }
+
+    if settings.shortcut != old.shortcut {
+        if let Err(e) = crate::register_shortcut(app.clone(), settings.shortcut.clone()) {
+            if settings.api != old.api {
+                let _ = api.configure(&old.api);
+            }
+            let _ = crate::register_shortcut(app.clone(), old.shortcut.clone());
+            return Err(e);
+        }
+    }
+
+    if settings.opacity != old.opacity {
+        apply_opacity(app, settings.opacity)?;
+    }
+
+    Ok(())
+}
+
+/// Applies side effects of changed settings and persists them
+fn apply(
+    app: &AppHandle,
+    store: &SettingsStore,
+    api: &ApiClient,
+    settings: Settings,
+) -> Result<Settings, AppError> {
+    settings.validate()?;
+    let old = store.get();
+
+    apply_effects(app, api, &old, &settings)?;
+    if let Err(e) = store.save(settings.clone()) {
+        let _ = apply_effects(app, api, &settings, &old);
+        return Err(e);
+    }
+
+    Ok(settings)
+}
+
+/// Rereads the settings file after an external change and notifies the UI
+pub fn reload(app: &AppHandle) {
+    let store = app.state::<SettingsStore>();
+    let api = app.state::<ApiClient>();
+
+    match store.reload(|old, settings| apply_effects(app, &api, old, settings)) {
+        Ok(Some(settings)) => {
+            let _ = settings.emit(app);
+        }
+        Ok(None) => {}
+        Err(error) => {
+            eprintln!("{}", error);
+            let _ = SettingsFileError { error }.emit(app);
+        }
+    }
+}
+
+/// Returns current settings
+#[tauri::command]
+pub fn get_settings(store: State<'_, SettingsStore>) -> Settings {
+    store.get()
+}
+
+/// Returns why the settings file on disk was rejected, if it was
+#[tauri::command]
+pub fn get_settings_error(store: State<'_, SettingsStore>) -> Option<AppError> {
+    store.error()
+}
+
+/// Updates settings and applies opacity, shortcut and API changes
+#[tauri::command]
+pub fn update_settings(
+    app: AppHandle,
+    store: State<'_, SettingsStore>,
+    api: State<'_, ApiClient>,
+    patch: SettingsPatch,
+) -> Result<Settings, AppError> {
+    let settings = patch.apply(&store.get());
+    apply(&app, &store, &api, settings)
+}
+
+/// Restores default settings
+#[tauri::command]
+pub fn reset_settings(
+    app: AppHandle,
+    store: State<'_, SettingsStore>,
+    api: State<'_, ApiClient>,
+) -> Result<Settings, AppError> {
+    apply(&app, &store, &api, Settings::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_syntax_error_position() {
+        let content = "{\n  \"version\": 1,\n  \"language\": \"en\"\n  \"opacity\": 80\n}";
+        let error = parse(Path::new("config.json"), content).unwrap_err();
+
+        let AppError::SettingsFile { line, column, .. } = error else {
+            panic!("unexpected error: {}", error);
+        };
+        assert_eq!((line, column), (4, 3));
+    }
+
+    #[test]
+    fn reports_mistyped_field_position() {
+        let content = "{\n  \"version\": 1,\n  \"opacity\": \"high\"\n}";
+        let error = parse(Path::new("config.json"), content).unwrap_err();
+
+        let AppError::SettingsFile { line, reason, .. } = error else {
+            panic!("unexpected error: {}", error);
+        };
+        assert_eq!(line, 3);
+        assert!(reason.starts_with("opacity: "), "{}", reason);
+    }
+
+    #[test]
+    fn encodes_launch_options_in_the_steam_url() {
+        assert_eq!(steam_launch_url("  "), "steam://rungameid/1422450");
+        assert_eq!(
+            steam_launch_url(" -novid +fps_max 240 -language=english "),
+            "steam://run/1422450//-novid%20%2Bfps_max%20240%20-language%3Denglish/"
+        );
+    }
+
+    #[test]
+    fn rejects_launch_options_with_special_characters() {
+        let settings = Settings {
+            launch_options: "-novid +fps_max 240 -language=english".into(),
+            ..Settings::default()
+        };
+        assert!(settings.validate().is_ok());
+
+        for options in ["-novid & calc", "-novid/", "\"-high\"", "%PATH%"] {
+            let settings = Settings {
+                launch_options: options.into(),
+                ..Settings::default()
+            };
+            let error = settings.validate().unwrap_err();
+            assert!(error.to_string().contains("launch_options"), "{}", error);
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("deadlyze-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn with_opacity(opacity: u32) -> Settings {
+        Settings {
+            opacity,
+            ..Settings::default()
+        }
+    }
+
+    fn stored_opacity(path: &Path) -> u32 {
+        let content = std::fs::read_to_string(path).unwrap();
+        parse(path, &content).unwrap().0.opacity
+    }
+
+    #[test]
+    fn keeps_newest_distinct_backups() {
+        let dir = temp_dir("settings-backups");
+        let path = dir.join(SETTINGS_FILE);
+        let store = SettingsStore::load(path.clone());
+
+        for opacity in [40, 50, 50, 60, 70, 80] {
+            store.save(with_opacity(opacity)).unwrap();
+        }
+        assert_eq!(stored_opacity(&path), 80);
+
+        // A file matching `.bak1` isn't backed up twice
+        std::fs::copy(backup_path(&path, 1), &path).unwrap();
+        rotate_backups(&path);
+
+        assert_eq!(stored_opacity(&backup_path(&path, 1)), 70);
+        assert_eq!(stored_opacity(&backup_path(&path, 2)), 60);
+        assert_eq!(stored_opacity(&backup_path(&path, 3)), 50);
+        assert!(!backup_path(&path, 4).exists());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn restores_newest_valid_backup() {
+        let dir = temp_dir("settings-restore");
+        let path = dir.join(SETTINGS_FILE);
+        std::fs::write(&path, "{ \"opacity\": ").unwrap();
+        std::fs::write(backup_path(&path, 1), "{ \"version\": 1, \"opacity\": 5 }").unwrap();
+        storage::save_json(&backup_path(&path, 2), &with_opacity(60)).unwrap();
+        storage::save_json(&backup_path(&path, 3), &with_opacity(70)).unwrap();
+
+        let store = SettingsStore::load(path.clone());
+        assert_eq!(store.get().opacity, 60);
+        assert!(matches!(store.error(), Some(AppError::SettingsFile { .. })));
+
+        // The rejected file is moved aside instead of becoming a backup
+        store.save(with_opacity(90)).unwrap();
+        assert!(store.error().is_none());
+        assert_eq!(stored_opacity(&path), 90);
+        assert_eq!(
+            std::fs::read_to_string(path.with_extension("json.corrupt")).unwrap(),
+            "{ \"opacity\": "
+        );
+        assert_eq!(stored_opacity(&backup_path(&path, 2)), 60);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn backs_up_valid_files_that_failed_to_apply() {
+        let dir = temp_dir("settings-apply");
+        let path = dir.join(SETTINGS_FILE);
+        let store = SettingsStore::load(path.clone());
+        store.save(with_opacity(80)).unwrap();
+
+        storage::save_json(&path, &with_opacity(70)).unwrap();
+        let error = AppError::invalid_settings("shortcut", "taken");
+        assert!(store.reload(|_, _| Err(error.clone())).is_err());
+        assert!(store.error().is_some());
+
+        store.save(with_opacity(90)).unwrap();
+        assert!(!path.with_extension("json.corrupt").exists());
+        assert_eq!(stored_opacity(&backup_path(&path, 1)), 70);
+        assert_eq!(stored_opacity(&path), 90);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn uses_defaults_without_valid_backup() {
+        let dir = temp_dir("settings-defaults");
+        let path = dir.join(SETTINGS_FILE);
+        std::fs::write(&path, "[]").unwrap();
+
+        let store = SettingsStore::load(path);
+        assert_eq!(store.get(), Settings::default());
+        assert!(store.error().is_some());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
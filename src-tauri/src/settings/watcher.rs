@@ -0,0 +1,61 @@
+//! Hot reload of the settings file edited outside the app
+//!
+//! Watches the app data directory rather than the file itself: atomic saves
+//! replace the file, which would drop a watch on its old inode.
+
+use std::path::Path;
+use std::sync::{mpsc, Mutex};
+use std::time::Duration;
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use tauri::AppHandle;
+
+use crate::error::AppError;
+
+/// Editors save in several steps (truncate, write, rename)
+const DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Keeps the file watcher alive (managed as Tauri state)
+pub struct SettingsWatcher {
+    _watcher: Mutex<RecommendedWatcher>,
+}
+
+/// Starts watching the settings file and reloads it after each change
+pub fn watch(app: &AppHandle, path: &Path) -> Result<SettingsWatcher, AppError> {
+    let dir = path
+        .parent()
+        .ok_or_else(|| AppError::storage(path, "no parent directory"))?;
+    std::fs::create_dir_all(dir).map_err(|e| AppError::storage(dir, e))?;
+
+    let file_name = path.file_name().map(ToOwned::to_owned);
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        let Ok(event) = event else {
+            return;
+        };
+        let is_settings = event
+            .paths
+            .iter()
+            .any(|path| path.file_name() == file_name.as_deref());
+        if is_settings && !event.kind.is_access() {
+            let _ = tx.send(());
+        }
+    })
+    .map_err(|e| AppError::storage(dir, e))?;
+    watcher
+        .watch(dir, RecursiveMode::NonRecursive)
+        .map_err(|e| AppError::storage(dir, e))?;
+
+    // Ends when the watcher (and with it the sender) is dropped
+    let app = app.clone();
+    std::thread::spawn(move || {
+        while rx.recv().is_ok() {
+            while rx.recv_timeout(DEBOUNCE).is_ok() {}
+            super::reload(&app);
+        }
+    });
+
+    Ok(SettingsWatcher {
+        _watcher: Mutex::new(watcher),
+    })
+}
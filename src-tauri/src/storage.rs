@@ -0,0 +1,91 @@
+//! JSON files in the app data directory
+
+use std::fs::FileTimes;
+use std::io::Write;
+use std::path::Path;
+use std::time::SystemTime;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::error::AppError;
+
+/// Loads JSON file, returning default value when the file doesn't exist yet
+pub fn load_json<T: DeserializeOwned + Default>(path: &Path) -> Result<T, AppError> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => serde_json::from_str(&content)
+            .map_err(|e| AppError::invalid_response(path.display(), e)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(T::default()),
+        Err(e) => Err(AppError::storage(path, e)),
+    }
+}
+
+/// Loads JSON file like [`load_json`]; a file that can't be parsed is moved
+/// aside to `<file>.corrupt` so the next save doesn't overwrite it
+pub fn load_json_or_set_aside<T: DeserializeOwned + Default>(path: &Path) -> Result<T, AppError> {
+    let error = match load_json(path) {
+        Err(error @ AppError::InvalidResponse { .. }) => error,
+        result => return result,
+    };
+
+    let corrupt_path = path.with_extension("json.corrupt");
+    std::fs::rename(path, &corrupt_path).map_err(|e| AppError::storage(&corrupt_path, e))?;
+    Err(error)
+}
+
+/// Writes JSON file atomically (temporary file flushed to disk + rename)
+pub fn save_json<T: Serialize>(path: &Path, value: &T) -> Result<(), AppError> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| AppError::storage(dir, e))?;
+    }
+
+    let content = serde_json::to_string_pretty(value).map_err(|e| AppError::storage(path, e))?;
+
+    let tmp_path = path.with_extension("json.tmp");
+    let mut file = std::fs::File::create(&tmp_path).map_err(|e| AppError::storage(&tmp_path, e))?;
+    file.write_all(content.as_bytes())
+        .and_then(|()| file.sync_all())
+        .map_err(|e| AppError::storage(&tmp_path, e))?;
+    drop(file);
+
+    std::fs::rename(&tmp_path, path).map_err(|e| AppError::storage(path, e))
+}
+
+/// Sets the access time of a cache file (drives least-recently-used eviction)
+pub fn touch(path: &Path) {
+    if let Ok(file) = std::fs::File::options().write(true).open(path) {
+        let _ = file.set_times(FileTimes::new().set_accessed(SystemTime::now()));
+    }
+}
+
+/// Removes least recently accessed files of `dir` until it fits into `max_bytes`
+pub fn evict_lru(dir: &Path, max_bytes: u64) -> Result<(), AppError> {
+    let mut entries = Vec::new();
+    let mut total: u64 = 0;
+
+    for entry in std::fs::read_dir(dir).map_err(|e| AppError::storage(dir, e))? {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        if let Ok(meta) = entry.metadata() {
+            if meta.is_file() {
+                let accessed = meta.accessed().unwrap_or(SystemTime::UNIX_EPOCH);
+                total += meta.len();
+                entries.push((accessed, meta.len(), entry.path()));
+            }
+        }
+    }
+
+    entries.sort_by_key(|(accessed, _, _)| *accessed);
+
+    for (_, len, path) in entries {
+        if total <= max_bytes {
+            break;
+        }
+        if std::fs::remove_file(&path).is_ok() {
+            total -= len;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,9 @@
+//! Unit tests of the build script code generators (`build/`)
+//!
+//! Cargo doesn't run tests of build scripts, so their modules are compiled
+//! into this test crate instead.
+
+#[path = "../build/bindings.rs"]
+mod bindings;
+#[path = "../build/openapi.rs"]
+mod openapi;
@@ -1,21 +1,22 @@
-import { useEffect } from "react";
-import { invoke } from "@tauri-apps/api/core";
-import { listen } from "@tauri-apps/api/event";
+import { useEffect, useState } from "react";
 import Sidebar from "./components/Layout/Sidebar/Sidebar";
 import PageContainer from "./components/Layout/PageContainer/PageContainer";
 import WindowControls from "./components/Layout/WindowControls/WindowControls";
+import SettingsErrorToast from "./components/Layout/SettingsErrorToast/SettingsErrorToast";
 import { ConfigManager } from "./utils/configManager";
-import { ShortcutManager } from "./utils/shortcutManager";
 import i18n from "./i18n/config";
+import { AppError, listenEvent } from "./bindings";
 
 function App() {
+  const [settingsError, setSettingsError] = useState<AppError | null>(null);
+
   useEffect(() => {
     const initializeApp = async () => {
       try {
         const settings = await ConfigManager.load();
         await i18n.changeLanguage(settings.language);
-        await invoke("set_window_opacity", { opacity: settings.opacity });
-        await ShortcutManager.register(settings.shortcut);
+
+        setSettingsError(await ConfigManager.loadError());
       } catch (error) {
         console.error("Failed to initialize app:", error);
       }
@@ -23,14 +24,13 @@ function App() {
 
     initializeApp();
 
-    // Listen for event to load saved opacity when showing window
-    const unlistenOpacity = listen("load-saved-opacity", async () => {
-      try {
-        const settings = await ConfigManager.load();
-        await invoke("set_window_opacity", { opacity: settings.opacity });
-      } catch (error) {
-        console.error("Failed to load saved opacity:", error);
-      }
+    // Файл настроек изменён вне приложения
+    const unlistenChanged = listenEvent("settings-changed", (event) => {
+      i18n.changeLanguage(event.payload.language);
+      setSettingsError(null);
+    });
+    const unlistenError = listenEvent("settings-file-error", (event) => {
+      setSettingsError(event.payload.error);
     });
 
     const disableContextMenu = (e: MouseEvent) => {
@@ -41,7 +41,8 @@ function App() {
 
     return () => {
       document.removeEventListener("contextmenu", disableContextMenu);
-      unlistenOpacity.then((fn) => fn());
+      unlistenChanged.then((unlisten) => unlisten());
+      unlistenError.then((unlisten) => unlisten());
     };
   }, []);
 
@@ -50,6 +51,10 @@ function App() {
       <Sidebar />
       <PageContainer />
       <WindowControls />
+      <SettingsErrorToast
+        error={settingsError}
+        onClose={() => setSettingsError(null)}
+      />
     </div>
   );
 }
@@ -0,0 +1,857 @@
+// Generated by src-tauri/build/bindings.rs from the backend sources. Do not edit.
+
+import { invoke } from "@tauri-apps/api/core";
+import { listen, type EventCallback, type UnlistenFn } from "@tauri-apps/api/event";
+
+/** Account history as sent to the UI */
+export interface AccountHistory {
+  account_id: number;
+  /** Oldest first */
+  snapshots: ProfileSnapshot[];
+  /** Oldest first */
+  changes: ProfileChange[];
+  /** Distinct persona names, most recent first */
+  known_names: string[];
+}
+
+/** API layer diagnostics as sent to the UI */
+export interface ApiDiagnostics {
+  requests: number;
+  /** Coalesced + not modified */
+  hits: number;
+  coalesced: number;
+  not_modified: number;
+  misses: number;
+  errors: number;
+  /** Share of requests served without downloading the body (0-1) */
+  hit_rate: number;
+  cached_responses: number;
+  /** Size of the cached responses as received */
+  cached_bytes: number;
+  in_flight: number;
+  /** Problems the app recovered from, newest first */
+  notices: Notice[];
+  /** Response fields that no longer match the expected schema */
+  schema_drift: DriftIssue[];
+}
+
+/** Connection settings; unset fields use the defaults */
+export interface ApiSettings {
+  /** API base URL (self-hosted mirror or local stand-in) */
+  base_url?: string | null;
+  api_key?: string | null;
+  user_agent?: string | null;
+  /** Whole request timeout */
+  timeout_secs?: number | null;
+  connect_timeout_secs?: number | null;
+  /** HTTP(S) or SOCKS5 proxy URL (`http://`, `https://`, `socks5://`) */
+  proxy?: string | null;
+  /**
+   * Patch notes RSS feed used while the API is down (URL or local file;
+   * the official forum feed when unset)
+   */
+  patch_feed?: string | null;
+}
+
+export interface AppError {
+  code: string;
+  key: string;
+  message: string;
+  context: Record<string, string | number>;
+}
+
+export interface AssetSnapshot {
+  /** `None` for the bundled snapshot */
+  fetched_at?: string | null;
+  heroes: HeroAsset[];
+  items: ItemAsset[];
+}
+
+/** Rank badge: first digits = tier, last digit = subtier (e.g. 116 = tier 11, subtier 6) */
+export interface Badge {
+  tier: number;
+  subtier: number;
+}
+
+/** Decoded badge distribution entry as sent to the UI */
+export interface BadgeCount {
+  badge: Badge;
+  total_matches: number;
+}
+
+/** Favourite build that couldn't be checked */
+export interface BuildCheckFailure {
+  hero_build_id: number;
+  error: AppError;
+}
+
+/** Build with popularity counters */
+export interface BuildEntry {
+  hero_build: HeroBuild;
+  num_daily_favorites: number;
+  num_favorites: number;
+  num_ignores: number;
+  num_reports: number;
+  num_weekly_favorites: number;
+}
+
+/** Build list filter from the UI */
+export interface BuildQuery {
+  hero_id?: number | null;
+  author_id?: number | null;
+  language?: number | null;
+  /** Only builds updated after this Unix timestamp */
+  updated_since?: number | null;
+  sort: BuildSort;
+  start?: number | null;
+  limit?: number | null;
+}
+
+export type BuildSort = "favorites" | "weekly_favorites" | "updated_at";
+
+/** Favourite build with a newer version available */
+export interface BuildUpdate {
+  hero_build_id: number;
+  name: string;
+  old_version: number;
+  new_version: number;
+}
+
+export type Confidence = "low" | "medium" | "high";
+
+export interface CounterAdvice {
+  enemy_hero_ids: number[];
+  /** Best first */
+  picks: CounterPick[];
+  /** Hero the items are suggested for (chosen hero or the best pick) */
+  item_hero_id?: number | null;
+  /**
+   * Best first; popularity is relative to the hero's matches against the
+   * enemy heroes, timings are not split
+   */
+  items: ItemSummary[];
+}
+
+export interface CounterPick {
+  hero_id: number;
+  /** Name and icon in the UI language */
+  hero?: ResolvedAsset | null;
+  /** Combined win rate against the enemy heroes */
+  win_rate: number;
+  /** Difference from the hero's overall win rate */
+  advantage: number;
+  matches: number;
+  /** Win rate lower bound used for ranking */
+  score: number;
+  confidence: Confidence;
+  matchups: Matchup[];
+}
+
+/** Aggregated drift of an endpoint field as sent to the UI */
+export interface DriftIssue {
+  /** Endpoint with numeric path segments replaced by `{id}` */
+  endpoint: string;
+  path: string;
+  kind: DriftKind;
+  /** Latest deserializer message */
+  detail?: string | null;
+  first_seen: string;
+  last_seen: string;
+  occurrences: number;
+}
+
+export type DriftKind = "unknown_field" | "type_mismatch";
+
+/** Result of checking favourites for newer versions */
+export interface FavoriteCheck {
+  updates: BuildUpdate[];
+  failures: BuildCheckFailure[];
+}
+
+export interface HeroAsset {
+  id: number;
+  class_name: string;
+  names: LocalizedNames;
+  hero_type?: string | null;
+  icon?: string | null;
+}
+
+export interface HeroBuild {
+  author_account_id: number;
+  description?: string | null;
+  /** Item categories and ability order */
+  details?: unknown | null;
+  hero_build_id: number;
+  hero_id: number;
+  language: number;
+  /** Unix timestamp */
+  last_updated_timestamp?: number | null;
+  name: string;
+  version: number;
+}
+
+/** Hero entry of the tier list */
+export interface HeroSummary {
+  hero_id: number;
+  /** Name and icon in the UI language */
+  hero?: ResolvedAsset | null;
+  matches: number;
+  win_rate: number;
+  pick_rate: number;
+  kda: number;
+  /** Win rate lower bound used for ranking (accounts for sample size) */
+  score: number;
+  tier: Tier;
+}
+
+export interface ItemAsset {
+  id: number;
+  class_name: string;
+  names: LocalizedNames;
+  /** Shop category: `weapon`, `vitality` or `spirit` */
+  slot?: string | null;
+  tier?: number | null;
+  cost?: number | null;
+  icon?: string | null;
+}
+
+export interface ItemPurchase {
+  game_time_s: number;
+  item_id: number;
+  /** 0 when the item was not sold */
+  sold_time_s: number;
+  upgrade_id: number;
+}
+
+/** Item analytics as sent to the UI */
+export interface ItemSummary {
+  item_id: number;
+  /** Name and icon in the UI language */
+  item?: ResolvedAsset | null;
+  matches: number;
+  /** Win rate when purchased */
+  win_rate: number;
+  /** Share of the hero's matches (all matches without hero filter) where the item was bought */
+  popularity: number;
+  /** Win rate lower bound used for ranking (accounts for sample size) */
+  score: number;
+  timings: TimingBucket[];
+}
+
+export interface LeaderboardPage {
+  region: Region;
+  hero_id?: number | null;
+  page: number;
+  page_size: number;
+  total_entries: number;
+  rows: LeaderboardRow[];
+  /** User's own row (also when it is outside the requested page) */
+  own_row?: LeaderboardRow | null;
+}
+
+/** Leaderboard request from the UI */
+export interface LeaderboardQuery {
+  region: Region;
+  hero_id?: number | null;
+  /** Zero-based page number */
+  page: number;
+  /** Rows per page (clamped to 1..=200) */
+  page_size?: number | null;
+  /** Current user's account (row gets highlighted) */
+  account_id?: number | null;
+}
+
+/** Leaderboard row as sent to the UI */
+export interface LeaderboardRow {
+  rank?: number | null;
+  account_name?: string | null;
+  account_id?: number | null;
+  personaname?: string | null;
+  avatar?: string | null;
+  badge_level?: number | null;
+  top_hero_ids: number[];
+  /** Names and icons of the top heroes in the UI language (unknown ones left out) */
+  top_heroes: ResolvedAsset[];
+  /** Row belongs to the current user */
+  is_own: boolean;
+}
+
+/** Names per app locale (`en`, `ru`) */
+export type LocalizedNames = Partial<Record<string, string>>;
+
+/** Match details as sent to the UI */
+export interface MatchDetails {
+  match_id: number;
+  /** Names and icons of the heroes and items in the match (UI language) */
+  assets: ResolvedAssets;
+  start_time: number;
+  duration_s: number;
+  winning_team: number;
+  game_mode: number;
+  match_mode: number;
+  teams: TeamDetails[];
+}
+
+/** Match history entry as sent to the UI */
+export type MatchHistoryEntry = PlayerMatch & {
+  won: boolean;
+};
+
+export interface MatchPlayer {
+  account_id: number;
+  assists: number;
+  deaths: number;
+  denies: number;
+  hero_id: number;
+  items: ItemPurchase[];
+  kills: number;
+  last_hits: number;
+  level: number;
+  net_worth: number;
+  /** Party the player queued with (0 when queued alone) */
+  party: number;
+  player_slot: number;
+  /** Periodic stat snapshots over the match */
+  stats: StatsSnapshot[];
+  team: number;
+}
+
+export interface Matchup {
+  enemy_hero_id: number;
+  /** Name and icon in the UI language */
+  enemy_hero?: ResolvedAsset | null;
+  matches: number;
+  win_rate: number;
+}
+
+/** Statistics with a single other player */
+export interface MateStats {
+  account_id: number;
+  games_with: number;
+  wins_with: number;
+  win_rate_with: number;
+  /** Games together in the same party (part of `games_with`) */
+  games_in_party: number;
+  wins_in_party: number;
+  win_rate_in_party: number;
+  games_against: number;
+  wins_against: number;
+  win_rate_against: number;
+  /** Unix timestamp of the latest shared match (`None` when only known from the API) */
+  last_played?: number | null;
+}
+
+export interface Notice {
+  /** Subsystem that recovered from the error (e.g. `patch_feed`) */
+  source: string;
+  error: AppError;
+  time: string;
+}
+
+/** Destroyed objective (walkers, base guardians, shrines, patron) */
+export interface Objective {
+  destroyed_time_s: number;
+  /** Team owning the objective */
+  team: number;
+  team_objective_id: number;
+}
+
+/** Patch note as sent to the UI: HTML content is replaced by sanitized Markdown */
+export interface PatchNoteView {
+  guid: string;
+  title: string;
+  author: string;
+  category: string;
+  link: string;
+  pub_date: string;
+  comments: number;
+  content_markdown: string;
+}
+
+/** Period during which a single patch was live */
+export interface PatchPeriod {
+  guid: string;
+  title: string;
+  started_at: string;
+  /** `None` for the current (latest) patch */
+  ended_at?: string | null;
+}
+
+/** Patch selector used by stats queries */
+export type PatchSelector = "current" | "previous";
+
+/** Chronological list of patch periods (oldest first) */
+export interface PatchTimeline {
+  periods: PatchPeriod[];
+}
+
+/** Search candidate as sent to the UI */
+export interface PlayerCandidate {
+  account_id: number;
+  personaname: string;
+  avatar: string;
+  profileurl: string;
+  countrycode?: string | null;
+  /** Current rank (`None` for players without ranked matches) */
+  mmr?: PlayerMMR | null;
+}
+
+/** Player rank */
+export interface PlayerMMR {
+  account_id: number;
+  division: number;
+  division_tier: number;
+  /** Latest match the rank was computed from */
+  match_id: number;
+  player_score: number;
+  rank: number;
+  start_time: number;
+}
+
+/** Match from the player's perspective */
+export interface PlayerMatch {
+  denies: number;
+  hero_id: number;
+  hero_level: number;
+  last_hits: number;
+  match_duration_s: number;
+  match_id: number;
+  /** Winning team */
+  match_result: number;
+  net_worth: number;
+  player_assists: number;
+  player_deaths: number;
+  player_kills: number;
+  player_team: number;
+  /** Unix timestamp */
+  start_time: number;
+}
+
+export interface Profile {
+  name: string;
+  settings: ProfileSettings;
+}
+
+/** Single change between two consecutive snapshots */
+export interface ProfileChange {
+  field: ProfileField;
+  old_value?: string | null;
+  new_value?: string | null;
+  changed_at: string;
+}
+
+export type ProfileField = "personaname" | "avatar" | "countrycode";
+
+export interface ProfileList {
+  /** Last saved or applied profile */
+  active?: string | null;
+  profiles: Profile[];
+}
+
+/** Settings carried by a profile */
+export interface ProfileSettings {
+  language: string;
+  opacity: number;
+  shortcut: string;
+  launch_options: string;
+}
+
+/** Tracked profile state; a new snapshot is stored only when it changes */
+export interface ProfileSnapshot {
+  personaname: string;
+  avatarfull: string;
+  countrycode?: string | null;
+  /** `last_updated` of the latest profile with this state */
+  last_updated: number;
+  first_seen: string;
+  last_seen: string;
+}
+
+export type Region = "Europe" | "Asia" | "NAmerica" | "SAmerica" | "Oceania";
+
+/** Hero or item reference resolved for a locale */
+export interface ResolvedAsset {
+  id: number;
+  name: string;
+  icon?: string | null;
+}
+
+/** Resolved IDs as sent to the UI */
+export interface ResolvedAssets {
+  heroes: Partial<Record<number, ResolvedAsset>>;
+  items: Partial<Record<number, ResolvedAsset>>;
+}
+
+export interface Settings {
+  /** Schema version of the stored file */
+  version: number;
+  language: string;
+  /** Window opacity in percent (20-100) */
+  opacity: number;
+  /** Show/hide window shortcut (e.g. "Alt+`") */
+  shortcut: string;
+  /** Steam launch options of the game (e.g. "-novid +fps_max 240") */
+  launch_options: string;
+  api: ApiSettings;
+}
+
+/** Settings file changed on disk but was rejected */
+export interface SettingsFileError {
+  error: AppError;
+}
+
+/** Partial update from the UI; missing fields keep their values */
+export interface SettingsPatch {
+  language?: string | null;
+  opacity?: number | null;
+  shortcut?: string | null;
+  launch_options?: string | null;
+  api?: ApiSettings | null;
+}
+
+/** Common analytics filter (rank bracket + time window) */
+export interface StatsFilter {
+  /** Lowest average badge of the match (inclusive) */
+  min_badge?: Badge | null;
+  /** Highest average badge of the match (inclusive) */
+  max_badge?: Badge | null;
+  window: StatsWindow;
+}
+
+export interface StatsSnapshot {
+  assists: number;
+  deaths: number;
+  denies: number;
+  kills: number;
+  last_hits: number;
+  level: number;
+  net_worth: number;
+  player_damage: number;
+  time_stamp_s: number;
+}
+
+/** Time window of analytics queries */
+export type StatsWindow =
+  | {
+      type: "last_days";
+      days: number;
+    }
+  | {
+      type: "patch";
+      patch: PatchSelector;
+    };
+
+/** Steam profile of a player */
+export interface SteamProfile {
+  account_id: number;
+  avatar: string;
+  avatarfull: string;
+  avatarmedium: string;
+  countrycode?: string | null;
+  /** Unix timestamp of the last profile update */
+  last_updated: number;
+  personaname: string;
+  profileurl: string;
+  realname?: string | null;
+}
+
+/** Result of a sync run */
+export interface SyncResult {
+  account_id: number;
+  new_matches: number;
+  total_matches: number;
+  last_match_id?: number | null;
+}
+
+/** Team summary for the post-game screen */
+export interface TeamDetails {
+  team: number;
+  won: boolean;
+  kills: number;
+  net_worth: number;
+  /** Sorted by net worth (highest first) */
+  players: MatchPlayer[];
+  /** Own objectives destroyed by the enemy, in destruction order */
+  objectives_lost: Objective[];
+}
+
+export type Tier = "S" | "A" | "B" | "C" | "D";
+
+/** Heroes ranked within a rank bracket and time window */
+export interface TierList {
+  filter: StatsFilter;
+  total_matches: number;
+  /** Best first */
+  heroes: HeroSummary[];
+}
+
+/** Win rate of an item by purchase time */
+export interface TimingBucket {
+  from_min: number;
+  /** `None` for the last bucket */
+  to_min?: number | null;
+  matches: number;
+  win_rate: number;
+}
+
+export function addFavoriteBuild(build: BuildEntry): Promise<void> {
+  return invoke("add_favorite_build", { build });
+}
+
+/**
+ * Refreshes favourites and emits `favorite-build-updated` for each newer version
+ *
+ * A build that fails to fetch is reported and doesn't stop the others.
+ */
+export function checkFavoriteBuilds(): Promise<FavoriteCheck> {
+  return invoke("check_favorite_builds");
+}
+
+export function deleteProfile(name: string): Promise<ProfileList> {
+  return invoke("delete_profile", { name });
+}
+
+/** Temporarily disables shortcut handling (used during editing) */
+export function disableShortcut(): Promise<void> {
+  return invoke("disable_shortcut");
+}
+
+/** Re-enables shortcut handling */
+export function enableShortcut(): Promise<void> {
+  return invoke("enable_shortcut");
+}
+
+/** Writes a profile to the `profiles` folder; returns the file path */
+export function exportProfile(name: string): Promise<string> {
+  return invoke("export_profile", { name });
+}
+
+/** Returns request coalescing and revalidation counters and recovered problems */
+export function getApiDiagnostics(): Promise<ApiDiagnostics> {
+  return invoke("get_api_diagnostics");
+}
+
+/** Returns full hero and item metadata */
+export function getAssets(): Promise<AssetSnapshot> {
+  return invoke("get_assets");
+}
+
+/** Returns player rank distribution for the time window */
+export function getBadgeDistribution(window?: StatsWindow | null): Promise<BadgeCount[]> {
+  return invoke("get_badge_distribution", { window });
+}
+
+/** Returns best heroes to pick against the enemy lineup and items to prioritize */
+export function getCounterPicks(
+  enemyHeroIds: number[],
+  excludedHeroIds?: number[] | null,
+  itemHeroId?: number | null,
+  filter?: StatsFilter | null,
+): Promise<CounterAdvice> {
+  return invoke("get_counter_picks", { enemyHeroIds, excludedHeroIds, itemHeroId, filter });
+}
+
+/** Returns locally stored favourite builds (available offline) */
+export function getFavoriteBuilds(): Promise<BuildEntry[]> {
+  return invoke("get_favorite_builds");
+}
+
+/** Returns hero tier list for the rank bracket and time window */
+export function getHeroTierList(filter?: StatsFilter | null): Promise<TierList> {
+  return invoke("get_hero_tier_list", { filter });
+}
+
+/** Returns item analytics filtered by hero, rank bracket and time window */
+export function getItemStats(
+  filter?: StatsFilter | null,
+  heroId?: number | null,
+): Promise<ItemSummary[]> {
+  return invoke("get_item_stats", { filter, heroId });
+}
+
+/** Returns a leaderboard page joined with Steam profiles */
+export function getLeaderboard(query: LeaderboardQuery): Promise<LeaderboardPage> {
+  return invoke("get_leaderboard", { query });
+}
+
+/** Returns post-game details of the match */
+export function getMatchDetails(matchId: number): Promise<MatchDetails> {
+  return invoke("get_match_details", { matchId });
+}
+
+/** Returns stored match history of the account, newest first */
+export function getMatchHistory(
+  accountId: number,
+  limit?: number | null,
+): Promise<MatchHistoryEntry[]> {
+  return invoke("get_match_history", { accountId, limit });
+}
+
+/**
+ * Returns players the account most often plays with and against
+ *
+ * Local stats cover synced matches (missing match metadata is downloaded);
+ * API party stats are merged in when the endpoint is available.
+ */
+export function getMates(accountId: number, minGames?: number | null): Promise<MateStats[]> {
+  return invoke("get_mates", { accountId, minGames });
+}
+
+/** Returns patch notes with sanitized content, newest first */
+export function getPatchNotes(): Promise<PatchNoteView[]> {
+  return invoke("get_patch_notes");
+}
+
+/** Returns the period of the selected patch (current or previous) */
+export function getPatchPeriod(patch: PatchSelector): Promise<PatchPeriod | null> {
+  return invoke("get_patch_period", { patch });
+}
+
+/** Returns patch timeline (when each patch started and ended) */
+export function getPatchTimeline(): Promise<PatchTimeline> {
+  return invoke("get_patch_timeline");
+}
+
+/** Returns stored persona name, avatar and country code changes of the account */
+export function getProfileHistory(accountId: number): Promise<AccountHistory> {
+  return invoke("get_profile_history", { accountId });
+}
+
+/** Returns schema drift seen in API responses during this session */
+export function getSchemaDrift(): Promise<DriftIssue[]> {
+  return invoke("get_schema_drift");
+}
+
+/** Returns current settings */
+export function getSettings(): Promise<Settings> {
+  return invoke("get_settings");
+}
+
+/** Returns why the settings file on disk was rejected, if it was */
+export function getSettingsError(): Promise<AppError | null> {
+  return invoke("get_settings_error");
+}
+
+/** Returns Steam profiles for the given accounts (recorded into profile history) */
+export function getSteamProfiles(accountIds: number[]): Promise<SteamProfile[]> {
+  return invoke("get_steam_profiles", { accountIds });
+}
+
+/** Returns top-performing items of the hero (rarely bought items are skipped) */
+export function getTopItems(
+  heroId: number,
+  filter?: StatsFilter | null,
+  limit?: number | null,
+): Promise<ItemSummary[]> {
+  return invoke("get_top_items", { heroId, filter, limit });
+}
+
+/** Imports a profile file from the `profiles` folder (replacing one with the same name) */
+export function importProfile(fileName: string): Promise<ProfileList> {
+  return invoke("import_profile", { fileName });
+}
+
+/** Checks if Deadlock game is currently running */
+export function isDeadlockRunning(): Promise<boolean> {
+  return invoke("is_deadlock_running");
+}
+
+/** Launches Deadlock game via Steam (App ID: 1422450) with the launch options from settings */
+export function launchDeadlock(): Promise<void> {
+  return invoke("launch_deadlock");
+}
+
+/** Returns community builds (most popular first by default) */
+export function listBuilds(query?: BuildQuery | null): Promise<BuildEntry[]> {
+  return invoke("list_builds", { query });
+}
+
+/** Returns profile files in the `profiles` folder that can be imported */
+export function listProfileFiles(): Promise<string[]> {
+  return invoke("list_profile_files");
+}
+
+/** Returns saved profiles and the active one */
+export function listProfiles(): Promise<ProfileList> {
+  return invoke("list_profiles");
+}
+
+/** Opens the application data folder in file explorer */
+export function openAppFolder(): Promise<void> {
+  return invoke("open_app_folder");
+}
+
+/** Downloads fresh metadata from the assets API */
+export function refreshAssets(): Promise<AssetSnapshot> {
+  return invoke("refresh_assets");
+}
+
+/** Registers global shortcut (unregisters previous) */
+export function registerShortcut(shortcut: string): Promise<void> {
+  return invoke("register_shortcut", { shortcut });
+}
+
+export function removeFavoriteBuild(heroBuildId: number): Promise<void> {
+  return invoke("remove_favorite_build", { heroBuildId });
+}
+
+/** Restores default settings */
+export function resetSettings(): Promise<Settings> {
+  return invoke("reset_settings");
+}
+
+/**
+ * Resolves hero and item IDs into localized names and icons (in the UI
+ * language unless given)
+ */
+export function resolveAssets(
+  heroIds: number[],
+  itemIds: number[],
+  language?: string | null,
+): Promise<ResolvedAssets> {
+  return invoke("resolve_assets", { heroIds, itemIds, language });
+}
+
+/** Saves current settings as a profile (replacing one with the same name) */
+export function saveProfile(name: string): Promise<ProfileList> {
+  return invoke("save_profile", { name });
+}
+
+/** Searches players by Steam name; `None` means the search was superseded by a newer one */
+export function searchPlayers(query: string): Promise<PlayerCandidate[] | null> {
+  return invoke("search_players", { query });
+}
+
+/** Sets window opacity (20-100) */
+export function setWindowOpacity(opacity: number): Promise<void> {
+  return invoke("set_window_opacity", { opacity });
+}
+
+/** Applies a profile, keeping the local API connection settings */
+export function switchProfile(name: string): Promise<Settings> {
+  return invoke("switch_profile", { name });
+}
+
+/** Downloads new matches of the account into local match history */
+export function syncMatchHistory(accountId: number): Promise<SyncResult> {
+  return invoke("sync_match_history", { accountId });
+}
+
+/** Updates settings and applies opacity, shortcut and API changes */
+export function updateSettings(patch: SettingsPatch): Promise<Settings> {
+  return invoke("update_settings", { patch });
+}
+
+/** Payload of every event emitted by the backend */
+export interface Events {
+  "favorite-build-updated": BuildUpdate;
+  "settings-changed": Settings;
+  "settings-file-error": SettingsFileError;
+}
+
+export function listenEvent<E extends keyof Events>(
+  event: E,
+  handler: EventCallback<Events[E]>,
+): Promise<UnlistenFn> {
+  return listen(event, handler);
+}
@@ -1,8 +1,8 @@
 import { useState, useEffect } from "react";
-import { invoke } from "@tauri-apps/api/core";
 import { useTranslation } from "react-i18next";
 import { ANIMATION_TIMINGS, GAME_STATUS_CHECK_INTERVAL } from "../../../constants";
 import { useGlitchEffect } from "../../../hooks";
+import { isDeadlockRunning, launchDeadlock } from "../../../bindings";
 
 const GLITCH_END_BEFORE =
   ANIMATION_TIMINGS.PAUSE_AFTER_GLITCH +
@@ -51,7 +51,7 @@ function LaunchHeader() {
   useEffect(() => {
     const checkGameStatus = async () => {
       try {
-        const running = await invoke<boolean>("is_deadlock_running");
+        const running = await isDeadlockRunning();
         setIsGameRunning(running);
       } catch (error) {
         console.error("Failed to check game status:", error);
@@ -80,7 +80,7 @@ function LaunchHeader() {
     setTimeout(() => setIsSpinning(false), ANIMATION_TIMINGS.LOGO_SPIN_DURATION);
 
     try {
-      await invoke("launch_deadlock");
+      await launchDeadlock();
     } catch (error) {
       console.error("Failed to launch game:", error);
     }
@@ -0,0 +1,79 @@
+import { useTranslation } from "react-i18next";
+import { RxCross2 } from "react-icons/rx";
+import { AppError } from "../../../bindings";
+import { errorMessage } from "../../../utils/errorMessage";
+
+interface SettingsErrorToastProps {
+  error: AppError | null;
+  onClose: () => void;
+}
+
+/**
+ * Сообщение об отклонённом файле настроек: приложение продолжает работать
+ * с последними корректными настройками
+ */
+function SettingsErrorToast({ error, onClose }: SettingsErrorToastProps) {
+  const { t } = useTranslation();
+
+  if (!error) return null;
+
+  return (
+    <div
+      role="alert"
+      className="fixed flex items-start gap-3 rounded-lg px-4 py-3"
+      style={{
+        bottom: "16px",
+        right: "16px",
+        maxWidth: "420px",
+        zIndex: 10000,
+        background: "linear-gradient(180deg, #5C2A2A 0%, #3D1C1C 100%)",
+        border: "1px solid rgba(255, 120, 120, 0.35)",
+        boxShadow: "0 0 20px 0 rgba(10, 30, 35, 0.57)",
+      }}
+    >
+      <div className="flex-1">
+        <h3
+          style={{
+            color: "#F5F5F5",
+            fontSize: "14px",
+            fontWeight: 600,
+            marginBottom: "4px",
+          }}
+        >
+          {t("settings.fileError.title")}
+        </h3>
+        <p
+          style={{
+            color: "#E0C5C5",
+            fontSize: "12px",
+            fontWeight: 400,
+            lineHeight: "1.5",
+            wordBreak: "break-word",
+          }}
+        >
+          {errorMessage(error)}
+        </p>
+        <p
+          style={{
+            color: "#B8A0A0",
+            fontSize: "12px",
+            fontWeight: 400,
+            lineHeight: "1.5",
+            marginTop: "4px",
+          }}
+        >
+          {t("settings.fileError.hint")}
+        </p>
+      </div>
+      <button
+        onClick={onClose}
+        className="transition-opacity hover:opacity-70"
+        aria-label="Close"
+      >
+        <RxCross2 size={18} color="#F5F5F5" />
+      </button>
+    </div>
+  );
+}
+
+export default SettingsErrorToast;
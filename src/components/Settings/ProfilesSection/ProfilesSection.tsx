@@ -0,0 +1,212 @@
+import { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import SettingItem from "../SettingItem/SettingItem";
+import { ProfileManager } from "../../../utils/profileManager";
+import { errorMessage } from "../../../utils/errorMessage";
+import { AppSettings, ProfileList } from "../../../types/settings";
+
+interface ProfilesSectionProps {
+  /** Вызывается после переключения профиля с применёнными настройками */
+  onSwitch: (settings: AppSettings) => void;
+}
+
+const CONTROL_STYLE = {
+  backgroundColor: "rgba(255, 255, 255, 0.08)",
+  border: "1px solid rgba(255, 255, 255, 0.15)",
+  color: "#C5C5C5",
+  fontSize: "14px",
+  fontWeight: 400,
+  outline: "none",
+};
+
+const BUTTON_CLASS =
+  "px-3 py-2 rounded transition-opacity hover:opacity-80 disabled:opacity-40";
+
+function ProfilesSection({ onSwitch }: ProfilesSectionProps) {
+  const { t } = useTranslation();
+  const [list, setList] = useState<ProfileList>({ active: null, profiles: [] });
+  const [name, setName] = useState("");
+  const [files, setFiles] = useState<string[]>([]);
+  const [selectedFile, setSelectedFile] = useState("");
+  const [status, setStatus] = useState<string | null>(null);
+
+  const loadFiles = async () => {
+    const files = await ProfileManager.listFiles();
+    setFiles(files);
+    setSelectedFile((current) =>
+      files.includes(current) ? current : (files[0] ?? ""),
+    );
+  };
+
+  useEffect(() => {
+    const load = async () => {
+      try {
+        const list = await ProfileManager.list();
+        setList(list);
+        setName(list.active ?? "");
+        await loadFiles();
+      } catch (error) {
+        console.error("Failed to load profiles:", error);
+      }
+    };
+
+    load();
+  }, []);
+
+  const run = async (action: () => Promise<void>) => {
+    try {
+      setStatus(null);
+      await action();
+    } catch (error) {
+      setStatus(errorMessage(error));
+    }
+  };
+
+  const handleSwitch = (profileName: string) =>
+    run(async () => {
+      const settings = await ProfileManager.switch(profileName);
+      setList((list) => ({ ...list, active: profileName }));
+      setName(profileName);
+      onSwitch(settings);
+    });
+
+  const handleSave = () =>
+    run(async () => {
+      setList(await ProfileManager.save(name));
+    });
+
+  const handleDelete = () =>
+    run(async () => {
+      setList(await ProfileManager.remove(name));
+      setName("");
+    });
+
+  const handleExport = () =>
+    run(async () => {
+      const path = await ProfileManager.export(name);
+      setStatus(t("settings.other.profiles.exported", { path }));
+      await loadFiles();
+    });
+
+  const handleImport = () =>
+    run(async () => {
+      setList(await ProfileManager.import(selectedFile));
+    });
+
+  const exists = list.profiles.some((profile) => profile.name === name);
+
+  return (
+    <div className="flex flex-col gap-6">
+      <SettingItem
+        title={t("settings.other.profiles.title")}
+        description={t("settings.other.profiles.description")}
+        control={
+          <div className="flex flex-col gap-2 items-end">
+            <select
+              value={list.active ?? ""}
+              onChange={(e) => handleSwitch(e.target.value)}
+              disabled={list.profiles.length === 0}
+              className="px-4 py-2 rounded cursor-pointer transition-opacity hover:opacity-80"
+              style={{ ...CONTROL_STYLE, minWidth: "200px" }}
+            >
+              <option value="" disabled style={{ backgroundColor: "#17433C" }}>
+                {t("settings.other.profiles.none")}
+              </option>
+              {list.profiles.map((profile) => (
+                <option
+                  key={profile.name}
+                  value={profile.name}
+                  style={{ backgroundColor: "#17433C" }}
+                >
+                  {profile.name}
+                </option>
+              ))}
+            </select>
+            <div className="flex gap-2">
+              <input
+                type="text"
+                value={name}
+                onChange={(e) => setName(e.target.value)}
+                placeholder={t("settings.other.profiles.namePlaceholder")}
+                className="px-3 py-2 rounded"
+                style={{ ...CONTROL_STYLE, width: "140px" }}
+              />
+              <button
+                onClick={handleSave}
+                disabled={!name.trim()}
+                className={BUTTON_CLASS}
+                style={CONTROL_STYLE}
+              >
+                {t("settings.other.profiles.save")}
+              </button>
+              <button
+                onClick={handleExport}
+                disabled={!exists}
+                className={BUTTON_CLASS}
+                style={CONTROL_STYLE}
+              >
+                {t("settings.other.profiles.export")}
+              </button>
+              <button
+                onClick={handleDelete}
+                disabled={!exists}
+                className={BUTTON_CLASS}
+                style={CONTROL_STYLE}
+              >
+                {t("settings.other.profiles.delete")}
+              </button>
+            </div>
+          </div>
+        }
+      />
+
+      <SettingItem
+        title={t("settings.other.profiles.import.title")}
+        description={t("settings.other.profiles.import.description")}
+        control={
+          <div className="flex gap-2">
+            <select
+              value={selectedFile}
+              onChange={(e) => setSelectedFile(e.target.value)}
+              onFocus={() => run(loadFiles)}
+              disabled={files.length === 0}
+              className="px-4 py-2 rounded cursor-pointer transition-opacity hover:opacity-80"
+              style={{ ...CONTROL_STYLE, minWidth: "140px" }}
+            >
+              {files.length === 0 && (
+                <option value="" style={{ backgroundColor: "#17433C" }}>
+                  {t("settings.other.profiles.import.none")}
+                </option>
+              )}
+              {files.map((file) => (
+                <option
+                  key={file}
+                  value={file}
+                  style={{ backgroundColor: "#17433C" }}
+                >
+                  {file}
+                </option>
+              ))}
+            </select>
+            <button
+              onClick={handleImport}
+              disabled={!selectedFile}
+              className={BUTTON_CLASS}
+              style={CONTROL_STYLE}
+            >
+              {t("settings.other.profiles.import.button")}
+            </button>
+          </div>
+        }
+      />
+
+      {status && (
+        <p style={{ color: "#9FA6AD", fontSize: "12px", lineHeight: "1.5" }}>
+          {status}
+        </p>
+      )}
+    </div>
+  );
+}
+
+export default ProfilesSection;
@@ -2,12 +2,14 @@ import { useEffect, useState } from "react";
 import { useTranslation } from "react-i18next";
 import { RxCross2 } from "react-icons/rx";
 import { FiFolder } from "react-icons/fi";
-import { invoke } from "@tauri-apps/api/core";
 import packageJson from "../../../../package.json";
 import SettingItem from "../SettingItem/SettingItem";
 import ShortcutInput from "../ShortcutInput/ShortcutInput";
+import ProfilesSection from "../ProfilesSection/ProfilesSection";
 import { ConfigManager } from "../../../utils/configManager";
 import { ShortcutManager } from "../../../utils/shortcutManager";
+import { listenEvent, openAppFolder } from "../../../bindings";
+import { AppSettings } from "../../../types/settings";
 
 interface SettingsModalProps {
   isOpen: boolean;
@@ -89,6 +91,8 @@ function SettingsModal({ isOpen, onClose }: SettingsModalProps) {
   const [currentLanguage, setCurrentLanguage] = useState(i18n.language);
   const [shortcut, setShortcut] = useState<string>("Alt+`");
   const [previousShortcut, setPreviousShortcut] = useState<string>("Alt+`");
+  const [launchOptions, setLaunchOptions] = useState("");
+  const [savedLaunchOptions, setSavedLaunchOptions] = useState("");
 
   useEffect(() => {
     setCurrentLanguage(i18n.language);
@@ -101,6 +105,8 @@ function SettingsModal({ isOpen, onClose }: SettingsModalProps) {
         setOpacity(settings.opacity);
         setShortcut(settings.shortcut);
         setPreviousShortcut(settings.shortcut);
+        setLaunchOptions(settings.launch_options);
+        setSavedLaunchOptions(settings.launch_options);
       } catch (error) {
         console.error("Failed to load settings:", error);
       }
@@ -111,6 +117,21 @@ function SettingsModal({ isOpen, onClose }: SettingsModalProps) {
     }
   }, [isOpen]);
 
+  // Файл настроек изменён вне приложения
+  useEffect(() => {
+    const unlisten = listenEvent("settings-changed", (event) => {
+      setOpacity(event.payload.opacity);
+      setShortcut(event.payload.shortcut);
+      setPreviousShortcut(event.payload.shortcut);
+      setLaunchOptions(event.payload.launch_options);
+      setSavedLaunchOptions(event.payload.launch_options);
+    });
+
+    return () => {
+      unlisten.then((unlisten) => unlisten());
+    };
+  }, []);
+
   useEffect(() => {
     if (isOpen) {
       setIsVisible(true);
@@ -133,17 +154,19 @@ function SettingsModal({ isOpen, onClose }: SettingsModalProps) {
     }
   };
 
+  const showSettings = async (settings: AppSettings) => {
+    await i18n.changeLanguage(settings.language);
+    setCurrentLanguage(settings.language);
+    setOpacity(settings.opacity);
+    setShortcut(settings.shortcut);
+    setPreviousShortcut(settings.shortcut);
+    setLaunchOptions(settings.launch_options);
+    setSavedLaunchOptions(settings.launch_options);
+  };
+
   const handleResetSettings = async () => {
     try {
-      await ConfigManager.reset();
-      const defaultSettings = await ConfigManager.load();
-      await i18n.changeLanguage(defaultSettings.language);
-      setCurrentLanguage(defaultSettings.language);
-      setOpacity(defaultSettings.opacity);
-      setShortcut(defaultSettings.shortcut);
-      setPreviousShortcut(defaultSettings.shortcut);
-      await invoke("set_window_opacity", { opacity: defaultSettings.opacity });
-      await ShortcutManager.register(defaultSettings.shortcut);
+      await showSettings(await ConfigManager.reset());
     } catch (error) {
       console.error("Failed to reset settings:", error);
     }
@@ -178,7 +201,6 @@ function SettingsModal({ isOpen, onClose }: SettingsModalProps) {
     }
 
     try {
-      await invoke("set_window_opacity", { opacity: finalValue });
       await ConfigManager.update({ opacity: finalValue });
     } catch (error) {
       console.error("Failed to save opacity:", error);
@@ -188,7 +210,6 @@ function SettingsModal({ isOpen, onClose }: SettingsModalProps) {
   const handleSliderChange = async (value: number) => {
     setOpacity(value);
     try {
-      await invoke("set_window_opacity", { opacity: value });
       await ConfigManager.update({ opacity: value });
     } catch (error) {
       console.error("Failed to update opacity:", error);
@@ -227,7 +248,6 @@ function SettingsModal({ isOpen, onClose }: SettingsModalProps) {
 
     try {
       await ConfigManager.update({ shortcut: shortcutToSave });
-      await ShortcutManager.update(shortcutToSave);
       setPreviousShortcut(shortcutToSave);
       setShortcut(shortcutToSave);
     } catch (error) {
@@ -236,6 +256,23 @@ function SettingsModal({ isOpen, onClose }: SettingsModalProps) {
     }
   };
 
+  const handleLaunchOptionsBlur = async () => {
+    const value = launchOptions.trim();
+    if (value === savedLaunchOptions) {
+      setLaunchOptions(value);
+      return;
+    }
+
+    try {
+      const settings = await ConfigManager.update({ launch_options: value });
+      setLaunchOptions(settings.launch_options);
+      setSavedLaunchOptions(settings.launch_options);
+    } catch (error) {
+      console.error("Failed to save launch options:", error);
+      setLaunchOptions(savedLaunchOptions);
+    }
+  };
+
   const calculateSliderPercent = () => {
     const value = typeof opacity === "number" ? opacity : OPACITY_MIN;
     return (
@@ -395,6 +432,31 @@ function SettingsModal({ isOpen, onClose }: SettingsModalProps) {
                   }
                 />
 
+                <SettingItem
+                  title={t("settings.general.launchOptions.title")}
+                  description={t("settings.general.launchOptions.description")}
+                  control={
+                    <input
+                      type="text"
+                      value={launchOptions}
+                      onChange={(e) => setLaunchOptions(e.target.value)}
+                      onBlur={handleLaunchOptionsBlur}
+                      placeholder="-novid"
+                      maxLength={256}
+                      className="px-3 py-2 rounded transition-opacity hover:opacity-80"
+                      style={{
+                        backgroundColor: "rgba(255, 255, 255, 0.08)",
+                        border: "1px solid rgba(255, 255, 255, 0.15)",
+                        color: "#C5C5C5",
+                        fontSize: "14px",
+                        fontWeight: 400,
+                        width: "200px",
+                        outline: "none",
+                      }}
+                    />
+                  }
+                />
+
                 <div className="flex flex-col gap-2">
                   <div className="flex items-start justify-between gap-6">
                     <div className="flex-1">
@@ -479,7 +541,7 @@ function SettingsModal({ isOpen, onClose }: SettingsModalProps) {
                     <button
                       onClick={async () => {
                         try {
-                          await invoke("open_app_folder");
+                          await openAppFolder();
                         } catch (error) {
                           console.error("Failed to open app folder:", error);
                         }
@@ -494,6 +556,8 @@ function SettingsModal({ isOpen, onClose }: SettingsModalProps) {
                     </button>
                   }
                 />
+
+                <ProfilesSection onSwitch={showSettings} />
               </div>
             )}
           </div>
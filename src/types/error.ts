@@ -0,0 +1,13 @@
+import type { AppError } from "../bindings";
+
+/** Error returned by Tauri commands: stable `code`, locale `key` and its `context` */
+export type { AppError };
+
+export function isAppError(error: unknown): error is AppError {
+  return (
+    typeof error === "object" &&
+    error !== null &&
+    "code" in error &&
+    "key" in error
+  );
+}
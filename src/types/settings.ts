@@ -1,11 +1,13 @@
-export interface AppSettings {
-  language: string;
-  opacity: number;
-  shortcut: string;
-}
-
-export const DEFAULT_SETTINGS: AppSettings = {
-  language: "ru",
-  opacity: 100,
-  shortcut: "Alt+`",
-};
+import type { ApiSettings, Settings, SettingsPatch } from "../bindings";
+
+/** Deadlock API connection settings (unset fields use defaults) */
+export type { ApiSettings };
+
+/** Settings stored and applied by the backend */
+export type AppSettings = Settings;
+
+/** Partial settings update (missing fields keep their values) */
+export type { SettingsPatch };
+
+/** Named settings profiles and the active one */
+export type { Profile, ProfileList } from "../bindings";
@@ -0,0 +1,15 @@
+import i18n from "../i18n/config";
+import { isAppError } from "../types/error";
+
+/**
+ * Возвращает локализованный текст ошибки команды
+ */
+export function errorMessage(error: unknown): string {
+  if (isAppError(error)) {
+    return i18n.t(error.key, { ...error.context, defaultValue: error.message });
+  }
+  if (error instanceof Error) {
+    return error.message;
+  }
+  return typeof error === "string" ? error : i18n.t("errors.unknown");
+}
@@ -0,0 +1,47 @@
+import {
+  deleteProfile,
+  exportProfile,
+  importProfile,
+  listProfileFiles,
+  listProfiles,
+  saveProfile,
+  switchProfile,
+} from "../bindings";
+import { AppSettings, ProfileList } from "../types/settings";
+
+/**
+ * Профили настроек: хранение и применение выполняет бэкенд,
+ * обмен файлами — через папку profiles в папке приложения
+ */
+export class ProfileManager {
+  static async list(): Promise<ProfileList> {
+    return listProfiles();
+  }
+
+  /** Сохраняет текущие настройки под указанным именем */
+  static async save(name: string): Promise<ProfileList> {
+    return saveProfile(name);
+  }
+
+  static async switch(name: string): Promise<AppSettings> {
+    return switchProfile(name);
+  }
+
+  static async remove(name: string): Promise<ProfileList> {
+    return deleteProfile(name);
+  }
+
+  /** Возвращает путь к файлу профиля (настройки подключения к API не входят) */
+  static async export(name: string): Promise<string> {
+    return exportProfile(name);
+  }
+
+  /** Файлы профилей, доступные для импорта */
+  static async listFiles(): Promise<string[]> {
+    return listProfileFiles();
+  }
+
+  static async import(fileName: string): Promise<ProfileList> {
+    return importProfile(fileName);
+  }
+}
@@ -1,4 +1,4 @@
-import { invoke } from "@tauri-apps/api/core";
+import { disableShortcut, enableShortcut, registerShortcut } from "../bindings";
 
 /**
  * Утилита для управления глобальными шорткатами
@@ -8,7 +8,7 @@ export class ShortcutManager {
    * Регистрирует глобальный шорткат
    */
   static async register(shortcut: string): Promise<void> {
-    await invoke("register_shortcut", { shortcut });
+    await registerShortcut(shortcut);
   }
 
   /**
@@ -16,20 +16,13 @@ export class ShortcutManager {
    * (используется при редактировании в UI)
    */
   static async disable(): Promise<void> {
-    await invoke("disable_shortcut");
+    await disableShortcut();
   }
 
   /**
    * Включает обработку шорткатов обратно
    */
   static async enable(): Promise<void> {
-    await invoke("enable_shortcut");
-  }
-
-  /**
-   * Обновляет шорткат (отменяет старый и регистрирует новый)
-   */
-  static async update(newShortcut: string): Promise<void> {
-    await this.register(newShortcut);
+    await enableShortcut();
   }
 }